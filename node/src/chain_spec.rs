@@ -18,9 +18,10 @@ use sp_runtime::{
 
 // Frontier
 use atleta_runtime::{
-    constants::currency::*, opaque::SessionKeys, AccountId, Balance, Block, MaxNominations,
-    RuntimeGenesisConfig, SS58Prefix, Signature, StakerStatus, BABE_GENESIS_EPOCH_CONFIG,
-    WASM_BINARY,
+    constants::{currency::*, time::DAYS},
+    opaque::SessionKeys,
+    AccountId, Balance, Block, MaxNominations, RuntimeGenesisConfig, SS58Prefix, Signature,
+    StakerStatus, BABE_GENESIS_EPOCH_CONFIG, WASM_BINARY,
 };
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 
@@ -152,6 +153,9 @@ fn testnet_genesis(
     // stakers: all validators and nominators.
     const ENDOWMENT: Balance = 75_000_000 * DOLLARS;
     const STASH: Balance = ENDOWMENT / 1000;
+    const FAUCET_POT: Balance = 10_000_000 * DOLLARS;
+    // faucet quota tier of partner developers
+    const PARTNER_TIER: u8 = 1;
     let mut rng = rand::thread_rng();
     let stakers = initial_authorities
         .iter()
@@ -217,7 +221,16 @@ fn testnet_genesis(
             "key": Some(sudo_key),
         },
         "balances": {
-            "balances": endowed_accounts.iter().cloned().map(|k| (k, ENDOWMENT)).collect::<Vec<_>>(),
+            "balances": endowed_accounts
+                .iter()
+                .cloned()
+                .map(|k| (k, ENDOWMENT))
+                .collect::<Vec<_>>(),
+        },
+        "faucet": {
+            "pot": FAUCET_POT,
+            "tiers": [(PARTNER_TIER, 100_000 * DOLLARS, DAYS)],
+            "allowlist": [(sudo_key, PARTNER_TIER)],
         },
         "babe": {
             "epochConfig": Some(BABE_GENESIS_EPOCH_CONFIG),
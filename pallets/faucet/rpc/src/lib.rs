@@ -0,0 +1,128 @@
+//! RPC interface for the faucet pallet.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiError, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::{Block as BlockT, MaybeDisplay};
+
+pub use pallet_faucet_runtime_api::FaucetApi as FaucetRuntimeApi;
+
+/// Faucet RPC methods.
+#[rpc(client, server)]
+pub trait FaucetApi<BlockHash, AccountId, Balance, BlockNumber> {
+    /// The amount `who` can still request in the current accumulation period.
+    #[method(name = "faucet_remainingQuota")]
+    fn remaining_quota(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+    /// The block the accumulation period of `who` ends at, `null` if nothing has been
+    /// requested during the current one.
+    #[method(name = "faucet_nextResetBlock")]
+    fn next_reset_block(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<BlockNumber>>;
+
+    /// The free balance of the faucet account.
+    #[method(name = "faucet_potBalance")]
+    fn pot_balance(&self, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+/// Provides RPC methods to query the faucet request limits.
+pub struct Faucet<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Faucet<C, Block> {
+    /// Creates a new instance of the Faucet RPC helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+    FaucetApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber> for Faucet<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: FaucetRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    AccountId: Codec + DeserializeOwned + Send + 'static,
+    Balance: Codec + MaybeDisplay + Copy + TryInto<NumberOrHex> + Send + Sync + 'static,
+    BlockNumber: Codec + Serialize + Send + 'static,
+{
+    fn remaining_quota(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<NumberOrHex> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let quota = self
+            .client
+            .runtime_api()
+            .remaining_quota(at_hash, who)
+            .map_err(|e| runtime_error("Unable to query remaining quota.", e))?;
+        try_into_rpc_balance(quota)
+    }
+
+    fn next_reset_block(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<BlockNumber>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .next_reset_block(at_hash, who)
+            .map_err(|e| runtime_error("Unable to query next reset block.", e))
+    }
+
+    fn pot_balance(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        let balance = self
+            .client
+            .runtime_api()
+            .pot_balance(at_hash)
+            .map_err(|e| runtime_error("Unable to query pot balance.", e))?;
+        try_into_rpc_balance(balance)
+    }
+}
+
+fn try_into_rpc_balance<Balance: MaybeDisplay + Copy + TryInto<NumberOrHex>>(
+    value: Balance,
+) -> RpcResult<NumberOrHex> {
+    value.try_into().map_err(|_| {
+        ErrorObject::owned(
+            Error::RuntimeError.into(),
+            format!("{} doesn't fit in NumberOrHex representation", value),
+            None::<()>,
+        )
+    })
+}
+
+fn runtime_error(message: &str, e: ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::RuntimeError.into(), message, Some(e.to_string()))
+}
@@ -0,0 +1,25 @@
+//! Runtime API definition for the faucet pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// The API to query the faucet request limits.
+    pub trait FaucetApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// The amount `who` can still request in the current accumulation period.
+        fn remaining_quota(who: AccountId) -> Balance;
+
+        /// The block the accumulation period of `who` ends at, `None` if nothing has been
+        /// requested during the current one.
+        fn next_reset_block(who: AccountId) -> Option<BlockNumber>;
+
+        /// The free balance of the faucet account.
+        fn pot_balance() -> Balance;
+    }
+}
@@ -4,7 +4,30 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Faucet;
 use frame_benchmarking::v2::*;
+use frame_support::traits::EnsureOrigin;
 use frame_system::RawOrigin;
+use sp_runtime::traits::TrailingZeroInput;
+
+/// Sets up the worst case of a request of the returned amount to the new account `who`: the
+/// account has a tier, both its request and the emission accumulate within the current period and
+/// the request leaves the pot with the minimum balance, so that `PotLow` is emitted.
+fn setup_request<T: Config>(who: &T::AccountId) -> BalanceOf<T> {
+    let max_emission = T::MaxEmissionPerPeriod::get();
+    let amount = max_emission / 4u32.into();
+    let now: BlockNumberFor<T> = 1u32.into();
+    frame_system::Pallet::<T>::set_block_number(now);
+
+    let period = Pallet::<T>::accumulation_period().max(now + now);
+    Tiers::<T>::insert(0, Tier { amount: max_emission, period });
+    AccountTier::<T>::insert(who, 0);
+    Requests::<T>::insert(who, (amount, now));
+    Emission::<T>::put((amount, now));
+
+    let pot = amount.saturating_add(T::Currency::minimum_balance());
+    let _ = T::Currency::make_free_balance_be(&Pallet::<T>::account_id(), pot);
+
+    amount
+}
 
 #[benchmarks]
 mod benchmarks {
@@ -12,13 +35,227 @@ mod benchmarks {
 
     #[benchmark]
     fn request_funds() {
-        let amount = 100u32.into();
+        let who: T::AccountId = account("requester", 0, 0);
+        let amount = setup_request::<T>(&who);
+
+        #[extrinsic_call]
+        _(RawOrigin::None, who.clone(), amount, 0u32.into(), 0);
+
+        assert_eq!(Requests::<T>::get(&who).0, amount + amount);
+        assert!(Pallet::<T>::pot_balance() < Pallet::<T>::faucet_amount());
+    }
+
+    #[benchmark]
+    fn set_pow_difficulty() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 16);
+
+        assert_eq!(PowDifficulty::<T>::get(), 16);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_funds_attested() {
+        let who: T::AccountId = account("requester", 0, 0);
+        let amount = setup_request::<T>(&who);
+        // the signature is checked by `validate_unsigned`, not by the call
+        let signature = T::AttestationSignature::decode(&mut TrailingZeroInput::zeroes())
+            .expect("infinite input; qed");
+        let attestation = Attestation {
+            expiry: 1u32.into(),
+            nonce: 0,
+            attester: account("attester", 0, 0),
+            signature,
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::None, who.clone(), amount, attestation);
+
+        assert_eq!(Requests::<T>::get(&who).0, amount + amount);
+        assert!(Pallet::<T>::pot_balance() < Pallet::<T>::faucet_amount());
+    }
+
+    #[benchmark]
+    fn add_attester() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let attester: T::AccountId = account("attester", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, attester.clone());
+
+        assert!(Attesters::<T>::contains_key(&attester));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_attester() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let attester: T::AccountId = account("attester", 0, 0);
+        Attesters::<T>::insert(&attester, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, attester.clone());
+
+        assert!(!Attesters::<T>::contains_key(&attester));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_funds_signed() {
         let caller: T::AccountId = whitelisted_caller();
-        let who: T::AccountId = caller.clone();
+        let amount = setup_request::<T>(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), amount);
+
+        assert_eq!(Requests::<T>::get(&caller).0, amount + amount);
+        assert!(Pallet::<T>::pot_balance() < Pallet::<T>::faucet_amount());
+    }
+
+    #[benchmark]
+    fn set_parameters() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let amount: BalanceOf<T> = 100u32.into();
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, amount, 10u32.into());
+
+        assert_eq!(FaucetAmount::<T>::get(), amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_tier() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let tier = Tier { amount: 100u32.into(), period: 10u32.into() };
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0, Some(tier));
+
+        assert_eq!(Tiers::<T>::get(0), Some(tier));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_account_tier() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let who: T::AccountId = account("requester", 0, 0);
+        Tiers::<T>::insert(0, Tier { amount: 100u32.into(), period: 10u32.into() });
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, who.clone(), Some(0));
+
+        assert_eq!(AccountTier::<T>::get(&who), Some(0));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn deny_account() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let who: T::AccountId = account("requester", 0, 0);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(Denylist::<T>::contains_key(&who));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn allow_account() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let who: T::AccountId = account("requester", 0, 0);
+        Denylist::<T>::insert(&who, ());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(!Denylist::<T>::contains_key(&who));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_idle_base() {
+        let last: T::AccountId = account("requester", 0, 0);
+        PruneCursor::<T>::put(&last);
+
+        #[block]
+        {
+            Pallet::<T>::prune_requests(0u32.into(), T::WeightInfo::on_idle_base());
+        }
+
+        assert_eq!(PruneCursor::<T>::get(), Some(last));
+    }
+
+    #[benchmark]
+    fn prune_request() {
+        // worst case: the requester has a tier and the request has expired
+        let who: T::AccountId = account("requester", 0, 0);
+        let period: BlockNumberFor<T> = 10u32.into();
+        Tiers::<T>::insert(0, Tier { amount: 100u32.into(), period });
+        AccountTier::<T>::insert(&who, 0);
+        Requests::<T>::insert(
+            &who,
+            (BalanceOf::<T>::from(100u32), BlockNumberFor::<T>::from(0u32)),
+        );
+
+        #[block]
+        {
+            let (who, request) = Requests::<T>::iter().next().unwrap();
+            Pallet::<T>::prune_request(&who, request, period);
+        }
+
+        assert!(!Requests::<T>::contains_key(&who));
+    }
+
+    #[benchmark]
+    fn prune_nonces_base() {
+        let last: T::AccountId = account("attester", 0, 0);
+        NoncePruneCursor::<T>::put((&last, 0u64));
+
+        #[block]
+        {
+            Pallet::<T>::prune_nonces(0u32.into(), T::WeightInfo::prune_nonces_base());
+        }
+
+        assert_eq!(NoncePruneCursor::<T>::get(), Some((last, 0)));
+    }
+
+    #[benchmark]
+    fn prune_nonce() {
+        // worst case: the nonce has expired
+        let attester: T::AccountId = account("attester", 0, 0);
+        UsedNonces::<T>::insert(&attester, 0, BlockNumberFor::<T>::from(0u32));
+
+        #[block]
+        {
+            let (attester, nonce, expiry) = UsedNonces::<T>::iter().next().unwrap();
+            Pallet::<T>::prune_nonce(&attester, nonce, expiry, 1u32.into());
+        }
+
+        assert!(!UsedNonces::<T>::contains_key(&attester, 0));
+    }
+
+    #[benchmark]
+    fn set_paused() -> Result<(), BenchmarkError> {
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
         #[extrinsic_call]
-        request_funds(RawOrigin::Signed(caller.clone()), who, amount);
+        _(origin as T::RuntimeOrigin, true);
 
-        assert!(Requests::<T>::contains_key(&caller));
+        assert!(Paused::<T>::get());
+        Ok(())
     }
 
     impl_benchmark_test_suite!(
@@ -1,7 +1,34 @@
 //! This pallet implements a straightforward faucet mechanism. It allows users to request funds exclusively for their own accounts.
-//! The origin should be signed.
+//! Requests are either unsigned (`request_funds`, `request_funds_attested`) or signed (`request_funds_signed`),
+//! the latter pays the signer only and is used by the EVM precompile.
 //!
-//! Users are limited to requesting up to the `Config::FaucetAmount` within a `Config::AccumulationPeriod` period.
+//! Users are limited to requesting up to the `FaucetAmount` within an `AccumulationPeriod` period.
+//! Both are stored on-chain, default to `Config::FaucetAmount` and `Config::AccumulationPeriod`
+//! and can be changed by `Config::AdminOrigin` with `set_parameters`.
+//! All users together can't receive more than `Config::MaxEmissionPerPeriod` within the same period.
+//!
+//! `Config::AdminOrigin` can define `Tiers` with their own amount and period and assign accounts
+//! to them with `AccountTier`, e.g. to give partner developers larger allowances.
+//! Accounts on the `Denylist` can't receive funds at all.
+//!
+//! `Requests` entries whose accumulation period has passed are pruned in `on_idle`.
+//!
+//! The genesis config can fund the pot, define tiers and an allowlist, and start the faucet
+//! paused. `Config::AdminOrigin` can pause and resume it with `set_paused`.
+//!
+//! Funds are paid out of the faucet account (the pot), which has to be funded beforehand,
+//! e.g. at genesis or by a treasury spend. The faucet never mints new tokens.
+//!
+//! Unsigned requests must carry a proof of work: a nonce such that the blake2 hash of
+//! `(block_hash, who, amount, nonce)` has at least `PowDifficulty` leading zero bits, where
+//! `block_hash` is the hash of a block not older than `Config::PowValidity` blocks.
+//! Use `Pallet::solve_pow` to find such a nonce.
+//!
+//! Alternatively, an unsigned request can carry an `Attestation`: a signature of
+//! `(genesis_hash, who, amount, expiry, nonce)` made by one of the `Attesters` registered by
+//! `Config::AdminOrigin`, e.g. an off-chain service checking a captcha. Every attestation nonce
+//! can be used only once. `UsedNonces` entries are pruned in `on_idle` once they've expired
+//! and when their attester is removed.
 //!
 //! Designed solely for use within test networks.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -20,24 +47,77 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::*;
 
 use frame_support::{
-    traits::{Currency, Get},
+    ensure,
+    traits::{Currency, ExistenceRequirement, Get},
+    weights::{Weight, WeightMeter},
     PalletId,
 };
-use sp_runtime::traits::AccountIdConversion;
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{AccountIdConversion, CheckedAdd, Hash, Saturating, Zero},
+    transaction_validity::InvalidTransaction,
+    DispatchResult, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// The maximum number of leading zero bits the request proof of work can require.
+pub const MAX_POW_DIFFICULTY: u32 = 64;
 
 type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+type TierOf<T> = Tier<BalanceOf<T>, BlockNumberFor<T>>;
+
+/// The `(total, start)` accumulator of the requested amount during a period.
+type AccumulatorOf<T> = (BalanceOf<T>, BlockNumberFor<T>);
+
+type AttestationOf<T> = Attestation<
+    <T as frame_system::Config>::AccountId,
+    BlockNumberFor<T>,
+    <T as Config>::AttestationSignature,
+>;
+
+/// An authorization of a faucet request signed by a trusted attester.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Attestation<AccountId, BlockNumber, Signature> {
+    /// The last block the attestation can be used at.
+    pub expiry: BlockNumber,
+    /// The attester's nonce protecting from replays.
+    pub nonce: u64,
+    /// The account ID of the attester.
+    pub attester: AccountId,
+    /// The attester's signature of `(genesis_hash, who, amount, expiry, nonce)`.
+    pub signature: Signature,
+}
+
+/// The identifier of a quota tier.
+pub type TierId = u8;
+
+/// The request limits of a quota tier.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Tier<Balance, BlockNumber> {
+    /// The maximum amount an account of the tier can request during `period`.
+    pub amount: Balance,
+    /// The accumulation period of the tier.
+    pub period: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::{TransactionValidity, *};
-    use frame_support::traits::ExistenceRequirement;
     use frame_system::pallet_prelude::*;
+    use sp_runtime::{
+        traits::{IdentifyAccount, Verify},
+        SaturatedConversion,
+    };
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_balances::Config {
@@ -54,16 +134,43 @@ pub mod pallet {
         #[pallet::constant]
         type PalletId: Get<PalletId>;
 
-        /// The period during which the user can't request more than `Config::MaxAmount`.
+        /// The period during which the user can't request more than `FaucetAmount`
+        /// until `AccumulationPeriod` is set.
         #[pallet::constant]
         type AccumulationPeriod: Get<BlockNumberFor<Self>>;
 
-        /// Faucet amount.
+        /// Faucet amount until `FaucetAmount` is set.
         #[pallet::constant]
         type FaucetAmount: Get<BalanceOf<Self>>;
+
+        /// The maximum amount sent to all users together during `AccumulationPeriod`.
+        #[pallet::constant]
+        type MaxEmissionPerPeriod: Get<BalanceOf<Self>>;
+
+        /// The origin allowed to change the faucet settings.
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The number of leading zero bits required from the request proof of work
+        /// until `PowDifficulty` is set. Must not exceed `MAX_POW_DIFFICULTY`.
+        #[pallet::constant]
+        type DefaultPowDifficulty: Get<u32>;
+
+        /// The number of blocks the block hash referenced by the proof of work stays valid.
+        #[pallet::constant]
+        type PowValidity: Get<BlockNumberFor<Self>>;
+
+        /// The signature attesters authorize requests with.
+        type AttestationSignature: Verify<Signer = Self::AttestationSigner> + Parameter;
+
+        /// The public key of attesters, identified by their account ID.
+        type AttestationSigner: IdentifyAccount<AccountId = Self::AccountId>;
     }
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Holding all requests by account.
@@ -77,11 +184,104 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// Total amount sent by the faucet and the block the current period started at.
+    #[pallet::storage]
+    #[pallet::getter(fn emission)]
+    pub type Emission<T: Config> = StorageValue<_, (BalanceOf<T>, BlockNumberFor<T>), ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultFaucetAmount<T: Config>() -> BalanceOf<T> {
+        T::FaucetAmount::get()
+    }
+
+    /// The maximum amount a user can request during `AccumulationPeriod`.
+    #[pallet::storage]
+    #[pallet::getter(fn faucet_amount)]
+    pub type FaucetAmount<T: Config> =
+        StorageValue<_, BalanceOf<T>, ValueQuery, DefaultFaucetAmount<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultAccumulationPeriod<T: Config>() -> BlockNumberFor<T> {
+        T::AccumulationPeriod::get()
+    }
+
+    /// The period during which the user can't request more than `FaucetAmount`.
+    #[pallet::storage]
+    #[pallet::getter(fn accumulation_period)]
+    pub type AccumulationPeriod<T: Config> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultAccumulationPeriod<T>>;
+
+    /// The quota tiers by their ID.
+    #[pallet::storage]
+    #[pallet::getter(fn tiers)]
+    pub type Tiers<T: Config> = StorageMap<_, Twox64Concat, TierId, TierOf<T>, OptionQuery>;
+
+    /// The quota tier of accounts with allowances other than the default one.
+    #[pallet::storage]
+    #[pallet::getter(fn account_tier)]
+    pub type AccountTier<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, TierId, OptionQuery>;
+
+    /// Whether requests are rejected.
+    #[pallet::storage]
+    #[pallet::getter(fn paused)]
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// The last account visited by the `Requests` pruning, `None` to start from the beginning.
+    #[pallet::storage]
+    pub type PruneCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+    /// The last nonce visited by the `UsedNonces` pruning, `None` to start from the beginning.
+    #[pallet::storage]
+    pub type NoncePruneCursor<T: Config> = StorageValue<_, (T::AccountId, u64), OptionQuery>;
+
+    /// Accounts not allowed to receive funds.
+    #[pallet::storage]
+    pub type Denylist<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultPowDifficulty<T: Config>() -> u32 {
+        T::DefaultPowDifficulty::get()
+    }
+
+    /// The number of leading zero bits required from the request proof of work.
+    #[pallet::storage]
+    #[pallet::getter(fn pow_difficulty)]
+    pub type PowDifficulty<T: Config> = StorageValue<_, u32, ValueQuery, DefaultPowDifficulty<T>>;
+
+    /// Accounts trusted to sign request attestations.
+    #[pallet::storage]
+    pub type Attesters<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Attestation nonces already used by every attester, with their expiry.
+    #[pallet::storage]
+    pub type UsedNonces<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        u64,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
+        /// Overrides `Config::FaucetAmount`.
+        pub faucet_amount: Option<BalanceOf<T>>,
+        /// Overrides `Config::AccumulationPeriod`.
+        pub accumulation_period: Option<BlockNumberFor<T>>,
+        /// The initial free balance of the faucet account, at least the existential deposit.
+        pub pot: BalanceOf<T>,
+        /// The initial quota tiers as `(tier, amount, period)`.
+        pub tiers: Vec<(TierId, BalanceOf<T>, BlockNumberFor<T>)>,
+        /// The initial accounts with their quota tier.
+        pub allowlist: Vec<(T::AccountId, TierId)>,
+        /// Whether the faucet starts paused.
+        pub paused: bool,
         #[serde(skip)]
-        _config: sp_std::marker::PhantomData<T>,
+        pub _config: sp_std::marker::PhantomData<T>,
     }
 
     #[pallet::genesis_build]
@@ -91,9 +291,30 @@ pub mod pallet {
             let account_id = <Pallet<T>>::account_id();
             let min = T::Currency::minimum_balance();
 
-            if T::Currency::free_balance(&account_id) < min {
-                let _ = T::Currency::make_free_balance_be(&account_id, min);
+            let pot = self.pot.max(min);
+
+            if T::Currency::free_balance(&account_id) < pot {
+                let _ = T::Currency::make_free_balance_be(&account_id, pot);
+            }
+
+            if let Some(amount) = self.faucet_amount {
+                FaucetAmount::<T>::put(amount);
+            }
+            if let Some(period) = self.accumulation_period {
+                assert!(!period.is_zero(), "Accumulation period is zero");
+                AccumulationPeriod::<T>::put(period);
+            }
+
+            for &(tier, amount, period) in &self.tiers {
+                assert!(!period.is_zero(), "Tier has a zero period");
+                Tiers::<T>::insert(tier, Tier { amount, period });
+            }
+            for (who, tier) in &self.allowlist {
+                assert!(Tiers::<T>::contains_key(tier), "Allowlisted account has an unknown tier");
+                AccountTier::<T>::insert(who, tier);
             }
+
+            Paused::<T>::put(self.paused);
         }
     }
 
@@ -107,21 +328,120 @@ pub mod pallet {
             /// The amount of funds.
             amount: BalanceOf<T>,
         },
+        /// The faucet account can't cover another full request. [balance]
+        PotLow {
+            /// The free balance left on the faucet account.
+            balance: BalanceOf<T>,
+        },
+        /// The proof of work difficulty changed. [difficulty]
+        PowDifficultySet {
+            /// The number of leading zero bits required.
+            difficulty: u32,
+        },
+        /// The attester has been registered. [attester]
+        AttesterAdded {
+            /// The account ID of the attester.
+            attester: T::AccountId,
+        },
+        /// The attester has been removed. [attester]
+        AttesterRemoved {
+            /// The account ID of the attester.
+            attester: T::AccountId,
+        },
+        /// The quota tier has been set or removed. [tier, limits]
+        TierSet {
+            /// The ID of the tier.
+            tier: TierId,
+            /// The new limits of the tier, `None` if it has been removed.
+            limits: Option<TierOf<T>>,
+        },
+        /// The account has been assigned to a quota tier. [who, tier]
+        AccountTierSet {
+            /// The account ID.
+            who: T::AccountId,
+            /// The ID of the tier, `None` for the default limits.
+            tier: Option<TierId>,
+        },
+        /// The account has been added to the denylist. [who]
+        AccountDenied {
+            /// The account ID.
+            who: T::AccountId,
+        },
+        /// The account has been removed from the denylist. [who]
+        AccountAllowed {
+            /// The account ID.
+            who: T::AccountId,
+        },
+        /// The faucet has been paused or resumed. [paused]
+        PauseSet {
+            /// Whether requests are rejected.
+            paused: bool,
+        },
+        /// The request limits changed. [amount, period]
+        ParametersUpdated {
+            /// The maximum amount a user can request during `period`.
+            amount: BalanceOf<T>,
+            /// The accumulation period.
+            period: BlockNumberFor<T>,
+        },
     }
 
     #[pallet::error]
     pub enum Error<T> {
-        /// Request amount more than `Config::MaxAmount`.
+        /// Request amount more than `FaucetAmount`.
         AmountTooHigh,
-        /// More than allowed funds requested during `Config::AccumulationPeriod`.
+        /// More than allowed funds requested during `AccumulationPeriod`.
         RequestLimitExceeded,
         /// No account to send funds.
         NoFaucetAccount,
+        /// The faucet account doesn't have enough funds to send.
+        FaucetDrained,
+        /// More than `Config::MaxEmissionPerPeriod` sent during `AccumulationPeriod`.
+        EmissionLimitExceeded,
+        /// The account is already an attester.
+        AlreadyAttester,
+        /// The account is not an attester.
+        NotAttester,
+        /// The quota tier doesn't exist.
+        UnknownTier,
+        /// The account is on the denylist.
+        AccountDenied,
+        /// The account is not on the denylist.
+        NotDenied,
+        /// The faucet is paused.
+        FaucetPaused,
+        /// The proof of work difficulty is more than `MAX_POW_DIFFICULTY`.
+        DifficultyTooHigh,
+        /// The accumulation period is zero.
+        InvalidPeriod,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let consumed = Self::prune_requests(now, remaining_weight);
+            consumed
+                .saturating_add(Self::prune_nonces(now, remaining_weight.saturating_sub(consumed)))
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::DefaultPowDifficulty::get() <= MAX_POW_DIFFICULTY,
+                "`DefaultPowDifficulty` must not exceed `MAX_POW_DIFFICULTY`"
+            );
+            assert!(
+                !T::AccumulationPeriod::get().is_zero(),
+                "`AccumulationPeriod` must not be zero"
+            );
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Request some funds.
+        ///
+        /// `block_number` and `nonce` are the proof of work checked by the transaction
+        /// validation, see `Pallet::solve_pow`.
         #[pallet::call_index(0)]
         #[pallet::weight(
         (<T as Config>::WeightInfo::request_funds(), DispatchClass::Normal, Pays::No)
@@ -130,32 +450,194 @@ pub mod pallet {
             origin: OriginFor<T>,
             who: T::AccountId,
             amount: BalanceOf<T>,
+            block_number: BlockNumberFor<T>,
+            nonce: u64,
         ) -> DispatchResult {
             ensure_none(origin)?;
+            // The proof of work is checked by `validate_unsigned`.
+            let _ = (block_number, nonce);
 
-            ensure!(amount <= T::FaucetAmount::get(), Error::<T>::AmountTooHigh);
+            Self::do_request_funds(who, amount)
+        }
 
-            let (balance, timestamp) = Requests::<T>::get(&who);
-            let now = frame_system::Pallet::<T>::block_number();
-            let period = now - timestamp;
+        /// Set the number of leading zero bits required from the request proof of work,
+        /// at most `MAX_POW_DIFFICULTY`.
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_pow_difficulty())]
+        pub fn set_pow_difficulty(origin: OriginFor<T>, difficulty: u32) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(difficulty <= MAX_POW_DIFFICULTY, Error::<T>::DifficultyTooHigh);
 
-            let (total, now) = if period >= T::AccumulationPeriod::get() {
-                (amount, now)
-            } else {
-                (balance + amount, timestamp)
-            };
+            PowDifficulty::<T>::put(difficulty);
 
-            ensure!(total <= T::FaucetAmount::get(), Error::<T>::RequestLimitExceeded);
+            Self::deposit_event(Event::PowDifficultySet { difficulty });
 
-            let account_id = Self::account_id();
+            Ok(())
+        }
 
-            let _ = T::Currency::make_free_balance_be(&account_id, amount);
-            let _ =
-                T::Currency::transfer(&account_id, &who, amount, ExistenceRequirement::AllowDeath);
+        /// Request some funds with an attestation checked by the transaction validation.
+        #[pallet::call_index(2)]
+        #[pallet::weight(
+        (<T as Config>::WeightInfo::request_funds_attested(), DispatchClass::Normal, Pays::No)
+        )]
+        pub fn request_funds_attested(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+            attestation: AttestationOf<T>,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
 
-            Requests::<T>::insert(&who, (total, now));
+            let Attestation { expiry, nonce, attester, .. } = attestation;
+            UsedNonces::<T>::insert(attester, nonce, expiry);
 
-            Self::deposit_event(Event::FundsSent { who, amount });
+            Self::do_request_funds(who, amount)
+        }
+
+        /// Register an account trusted to sign request attestations.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::add_attester())]
+        pub fn add_attester(origin: OriginFor<T>, attester: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(!Attesters::<T>::contains_key(&attester), Error::<T>::AlreadyAttester);
+            Attesters::<T>::insert(&attester, ());
+
+            Self::deposit_event(Event::AttesterAdded { attester });
+
+            Ok(())
+        }
+
+        /// Remove an account from the attesters.
+        ///
+        /// Its `UsedNonces` are kept until they expire, so its attestations can't be replayed if
+        /// it's added back.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_attester())]
+        pub fn remove_attester(origin: OriginFor<T>, attester: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(Attesters::<T>::contains_key(&attester), Error::<T>::NotAttester);
+            Attesters::<T>::remove(&attester);
+
+            Self::deposit_event(Event::AttesterRemoved { attester });
+
+            Ok(())
+        }
+
+        /// Request some funds for the signer, e.g. from the EVM.
+        ///
+        /// The request is limited the same way as the unsigned one.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_funds_signed())]
+        pub fn request_funds_signed(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_request_funds(who, amount)
+        }
+
+        /// Set the maximum amount a user can request during the accumulation `period`, which
+        /// must not be zero.
+        ///
+        /// Running accumulations keep their start block.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_parameters())]
+        pub fn set_parameters(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+            period: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+
+            FaucetAmount::<T>::put(amount);
+            AccumulationPeriod::<T>::put(period);
+
+            Self::deposit_event(Event::ParametersUpdated { amount, period });
+
+            Ok(())
+        }
+
+        /// Set the limits of the quota `tier`, or remove it with `None`. The period of the limits
+        /// must not be zero.
+        ///
+        /// Accounts assigned to a removed tier fall back to the default limits.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_tier())]
+        pub fn set_tier(
+            origin: OriginFor<T>,
+            tier: TierId,
+            limits: Option<TierOf<T>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if let Some(limits) = limits {
+                ensure!(!limits.period.is_zero(), Error::<T>::InvalidPeriod);
+            }
+
+            Tiers::<T>::set(tier, limits);
+
+            Self::deposit_event(Event::TierSet { tier, limits });
+
+            Ok(())
+        }
+
+        /// Assign the account to the quota `tier`, or to the default limits with `None`.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_account_tier())]
+        pub fn set_account_tier(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+            tier: Option<TierId>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            if let Some(tier) = tier {
+                ensure!(Tiers::<T>::contains_key(tier), Error::<T>::UnknownTier);
+            }
+            AccountTier::<T>::set(&who, tier);
+
+            Self::deposit_event(Event::AccountTierSet { who, tier });
+
+            Ok(())
+        }
+
+        /// Forbid the account to receive funds.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::deny_account())]
+        pub fn deny_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(!Denylist::<T>::contains_key(&who), Error::<T>::AccountDenied);
+            Denylist::<T>::insert(&who, ());
+
+            Self::deposit_event(Event::AccountDenied { who });
+
+            Ok(())
+        }
+
+        /// Remove the account from the denylist.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::allow_account())]
+        pub fn allow_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            ensure!(Denylist::<T>::contains_key(&who), Error::<T>::NotDenied);
+            Denylist::<T>::remove(&who);
+
+            Self::deposit_event(Event::AccountAllowed { who });
+
+            Ok(())
+        }
+
+        /// Pause or resume the faucet.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_paused())]
+        pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::put(paused);
+
+            Self::deposit_event(Event::PauseSet { paused });
 
             Ok(())
         }
@@ -166,11 +648,61 @@ pub mod pallet {
         type Call = Call<T>;
 
         fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            // Requests failing at dispatch would enter blocks for free, so the limits are checked
+            // before the more expensive proof of work and signature verification.
+            match call {
+                Call::request_funds { who, amount, .. }
+                | Call::request_funds_attested { who, amount, .. } => {
+                    if let Err(error) = Self::check_request(who, *amount) {
+                        return Self::request_invalidity(error).into();
+                    }
+                },
+                _ => return InvalidTransaction::Call.into(),
+            }
+
             match call {
-                Call::request_funds { who, amount } => ValidTransaction::with_tag_prefix("Faucet")
-                    .and_provides((who, amount))
-                    .propagate(true)
-                    .build(),
+                Call::request_funds { who, amount, block_number, nonce } => {
+                    let now = frame_system::Pallet::<T>::block_number();
+                    if *block_number >= now {
+                        return InvalidTransaction::Future.into();
+                    }
+                    let age = now - *block_number;
+                    if age > T::PowValidity::get() {
+                        return InvalidTransaction::Stale.into();
+                    }
+
+                    let block_hash = frame_system::Pallet::<T>::block_hash(block_number);
+                    if Self::pow_work(&block_hash, who, *amount, *nonce) < Self::pow_difficulty() {
+                        return InvalidTransaction::BadProof.into();
+                    }
+
+                    ValidTransaction::with_tag_prefix("Faucet")
+                        .and_provides((who, amount))
+                        .longevity((T::PowValidity::get() - age).saturated_into::<u64>().max(1))
+                        .propagate(true)
+                        .build()
+                },
+                Call::request_funds_attested { who, amount, attestation } => {
+                    let Attestation { expiry, nonce, attester, signature } = attestation;
+                    let now = frame_system::Pallet::<T>::block_number();
+                    if *expiry < now || UsedNonces::<T>::contains_key(attester, nonce) {
+                        return InvalidTransaction::Stale.into();
+                    }
+                    if !Attesters::<T>::contains_key(attester) {
+                        return InvalidTransaction::BadSigner.into();
+                    }
+
+                    let payload = Self::attestation_payload(who, *amount, *expiry, *nonce);
+                    if !signature.verify(&payload[..], attester) {
+                        return InvalidTransaction::BadProof.into();
+                    }
+
+                    ValidTransaction::with_tag_prefix("Faucet")
+                        .and_provides((attester, nonce))
+                        .longevity((*expiry - now).saturated_into::<u64>().saturating_add(1))
+                        .propagate(true)
+                        .build()
+                },
                 _ => InvalidTransaction::Call.into(),
             }
         }
@@ -182,4 +714,252 @@ impl<T: Config> Pallet<T> {
     pub fn account_id() -> T::AccountId {
         T::PalletId::get().into_account_truncating()
     }
+
+    /// The request limits of `who`: the ones of its tier if any, the default ones otherwise.
+    pub fn limits(who: &T::AccountId) -> TierOf<T> {
+        AccountTier::<T>::get(who).and_then(Tiers::<T>::get).unwrap_or_else(|| Tier {
+            amount: Self::faucet_amount(),
+            period: Self::accumulation_period(),
+        })
+    }
+
+    /// The amount `who` can still request in the current period, also limited by the amount
+    /// left to all users by `Config::MaxEmissionPerPeriod`.
+    pub fn remaining_quota(who: &T::AccountId) -> BalanceOf<T> {
+        if Self::paused() || Denylist::<T>::contains_key(who) {
+            return Zero::zero();
+        }
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let limits = Self::limits(who);
+        let requested = Self::accumulated(Requests::<T>::get(who), now, limits.period);
+        let emitted = Self::accumulated(Emission::<T>::get(), now, Self::accumulation_period());
+
+        limits
+            .amount
+            .saturating_sub(requested)
+            .min(T::MaxEmissionPerPeriod::get().saturating_sub(emitted))
+    }
+
+    /// The block the accumulation period of `who` ends at, `None` if nothing has been
+    /// requested during the current one.
+    pub fn next_reset_block(who: &T::AccountId) -> Option<BlockNumberFor<T>> {
+        let now = frame_system::Pallet::<T>::block_number();
+        let period = Self::limits(who).period;
+        let (total, start) = Requests::<T>::get(who);
+
+        (!Self::accumulated((total, start), now, period).is_zero())
+            .then(|| start.saturating_add(period))
+    }
+
+    /// The free balance of the faucet account.
+    pub fn pot_balance() -> BalanceOf<T> {
+        T::Currency::free_balance(&Self::account_id())
+    }
+
+    /// Removes the `Requests` entries whose accumulation period has passed, starting after
+    /// `PruneCursor`, within `limit`. Returns the consumed weight.
+    pub(crate) fn prune_requests(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+        let mut meter = WeightMeter::with_limit(limit);
+        if meter.try_consume(T::WeightInfo::on_idle_base()).is_err() {
+            return Weight::zero();
+        }
+
+        let mut cursor = PruneCursor::<T>::get();
+        let mut requests = match &cursor {
+            Some(last) => Requests::<T>::iter_from(Requests::<T>::hashed_key_for(last)),
+            None => Requests::<T>::iter(),
+        };
+        while meter.try_consume(T::WeightInfo::prune_request()).is_ok() {
+            let Some((who, request)) = requests.next() else {
+                cursor = None;
+                break;
+            };
+            Self::prune_request(&who, request, now);
+            cursor = Some(who);
+        }
+        PruneCursor::<T>::set(cursor);
+
+        meter.consumed()
+    }
+
+    /// Removes the `UsedNonces` entries expired at `now`, starting after `NoncePruneCursor`,
+    /// within `limit`. Returns the consumed weight.
+    pub(crate) fn prune_nonces(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+        let mut meter = WeightMeter::with_limit(limit);
+        if meter.try_consume(T::WeightInfo::prune_nonces_base()).is_err() {
+            return Weight::zero();
+        }
+
+        let mut cursor = NoncePruneCursor::<T>::get();
+        let mut nonces = match &cursor {
+            Some((attester, nonce)) => {
+                UsedNonces::<T>::iter_from(UsedNonces::<T>::hashed_key_for(attester, nonce))
+            },
+            None => UsedNonces::<T>::iter(),
+        };
+        while meter.try_consume(T::WeightInfo::prune_nonce()).is_ok() {
+            let Some((attester, nonce, expiry)) = nonces.next() else {
+                cursor = None;
+                break;
+            };
+            Self::prune_nonce(&attester, nonce, expiry, now);
+            cursor = Some((attester, nonce));
+        }
+        NoncePruneCursor::<T>::set(cursor);
+
+        meter.consumed()
+    }
+
+    /// Removes the used `nonce` of `attester` if its attestation has expired at `now`.
+    pub(crate) fn prune_nonce(
+        attester: &T::AccountId,
+        nonce: u64,
+        expiry: BlockNumberFor<T>,
+        now: BlockNumberFor<T>,
+    ) {
+        // expired attestations are rejected by `validate_unsigned` anyway
+        if expiry < now {
+            UsedNonces::<T>::remove(attester, nonce);
+        }
+    }
+
+    /// Removes the request of `who` if its accumulation period has passed at `now`.
+    pub(crate) fn prune_request(
+        who: &T::AccountId,
+        request: (BalanceOf<T>, BlockNumberFor<T>),
+        now: BlockNumberFor<T>,
+    ) {
+        if Self::accumulated(request, now, Self::limits(who).period).is_zero() {
+            Requests::<T>::remove(who);
+        }
+    }
+
+    /// The total of the `(total, start)` accumulator at `now`.
+    fn accumulated(
+        (total, start): (BalanceOf<T>, BlockNumberFor<T>),
+        now: BlockNumberFor<T>,
+        period: BlockNumberFor<T>,
+    ) -> BalanceOf<T> {
+        if now.saturating_sub(start) >= period {
+            Zero::zero()
+        } else {
+            total
+        }
+    }
+
+    /// Adds `amount` to the `(total, start)` accumulator, restarting it once
+    /// `period` has passed since `start`.
+    fn accumulate(
+        (total, start): (BalanceOf<T>, BlockNumberFor<T>),
+        amount: BalanceOf<T>,
+        now: BlockNumberFor<T>,
+        period: BlockNumberFor<T>,
+    ) -> Option<(BalanceOf<T>, BlockNumberFor<T>)> {
+        if now - start >= period {
+            Some((amount, now))
+        } else {
+            total.checked_add(&amount).map(|total| (total, start))
+        }
+    }
+
+    /// Finds a proof of work nonce for a request of `amount` to `who` referencing the block
+    /// with `block_hash`, so that it has at least `difficulty` leading zero bits.
+    ///
+    /// `difficulty` must not exceed `MAX_POW_DIFFICULTY`, higher values may never be met.
+    pub fn solve_pow(
+        block_hash: &T::Hash,
+        who: &T::AccountId,
+        amount: BalanceOf<T>,
+        difficulty: u32,
+    ) -> u64 {
+        let mut nonce = 0;
+        while Self::pow_work(block_hash, who, amount, nonce) < difficulty {
+            nonce += 1;
+        }
+        nonce
+    }
+
+    /// The number of leading zero bits of the proof of work hash.
+    fn pow_work(block_hash: &T::Hash, who: &T::AccountId, amount: BalanceOf<T>, nonce: u64) -> u32 {
+        let hash = (block_hash, who, amount, nonce).using_encoded(T::Hashing::hash);
+        let mut zeros = 0;
+        for byte in hash.as_ref() {
+            zeros += byte.leading_zeros();
+            if *byte != 0 {
+                break;
+            }
+        }
+        zeros
+    }
+
+    /// The message an attester signs to authorize a request of `amount` to `who`.
+    ///
+    /// It includes the genesis hash, so that an attestation can't be replayed on another chain.
+    pub fn attestation_payload(
+        who: &T::AccountId,
+        amount: BalanceOf<T>,
+        expiry: BlockNumberFor<T>,
+        nonce: u64,
+    ) -> Vec<u8> {
+        let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+        (genesis_hash, who, amount, expiry, nonce).encode()
+    }
+
+    /// Checks a request of `amount` to `who` against the request limits without changing
+    /// anything. Returns the updated `Requests` entry of `who` and `Emission`.
+    fn check_request(
+        who: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> Result<(AccumulatorOf<T>, AccumulatorOf<T>), Error<T>> {
+        ensure!(!Self::paused(), Error::<T>::FaucetPaused);
+        ensure!(!Denylist::<T>::contains_key(who), Error::<T>::AccountDenied);
+
+        let limits = Self::limits(who);
+        ensure!(amount <= limits.amount, Error::<T>::AmountTooHigh);
+
+        let now = frame_system::Pallet::<T>::block_number();
+        let request = Self::accumulate(Requests::<T>::get(who), amount, now, limits.period)
+            .filter(|(total, _)| *total <= limits.amount)
+            .ok_or(Error::<T>::RequestLimitExceeded)?;
+        let emission =
+            Self::accumulate(Emission::<T>::get(), amount, now, Self::accumulation_period())
+                .filter(|(total, _)| *total <= T::MaxEmissionPerPeriod::get())
+                .ok_or(Error::<T>::EmissionLimitExceeded)?;
+
+        ensure!(Self::pot_balance() >= amount, Error::<T>::FaucetDrained);
+
+        Ok((request, emission))
+    }
+
+    /// The reason an unsigned request rejected by `check_request` is invalid.
+    fn request_invalidity(error: Error<T>) -> InvalidTransaction {
+        match error {
+            Error::<T>::RequestLimitExceeded
+            | Error::<T>::EmissionLimitExceeded
+            | Error::<T>::FaucetDrained => InvalidTransaction::ExhaustsResources,
+            _ => InvalidTransaction::Call,
+        }
+    }
+
+    /// Sends `amount` from the faucet account to `who` within the request limits.
+    fn do_request_funds(who: T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+        let (request, emission) = Self::check_request(&who, amount)?;
+
+        let account_id = Self::account_id();
+        T::Currency::transfer(&account_id, &who, amount, ExistenceRequirement::KeepAlive)
+            .map_err(|_| Error::<T>::FaucetDrained)?;
+
+        Requests::<T>::insert(&who, request);
+        Emission::<T>::put(emission);
+
+        Self::deposit_event(Event::FundsSent { who, amount });
+
+        let balance = T::Currency::free_balance(&account_id);
+        if balance < Self::faucet_amount() {
+            Self::deposit_event(Event::PotLow { balance });
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,32 @@
+//! Storage migrations for the faucet pallet.
+
+use super::*;
+use frame_support::{
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// Moves the request limits from the runtime constants to storage.
+pub mod v1 {
+    use super::*;
+
+    /// Stores the current `Config::FaucetAmount` and `Config::AccumulationPeriod`, so that
+    /// changing the defaults in a later runtime doesn't silently change the limits.
+    pub struct MigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain = Pallet::<T>::on_chain_storage_version();
+            if on_chain >= 1 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            FaucetAmount::<T>::put(T::FaucetAmount::get());
+            AccumulationPeriod::<T>::put(T::AccumulationPeriod::get());
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            T::DbWeight::get().reads_writes(1, 3)
+        }
+    }
+}
@@ -4,15 +4,16 @@ use frame_support::{
     traits::{ConstU16, ConstU32, ConstU64, Everything},
     PalletId,
 };
-use frame_system::pallet_prelude::*;
+use frame_system::{pallet_prelude::*, EnsureRoot};
 use sp_core::H256;
 use sp_runtime::{
+    testing::{TestSignature, UintAuthorityId},
     traits::{BlakeTwo256, IdentityLookup},
     BuildStorage,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
-pub(crate) type AccountId = u32;
+pub(crate) type AccountId = u64;
 pub(crate) type Balance = u32;
 type Nonce = u32;
 
@@ -79,24 +80,49 @@ impl pallet_balances::Config for Test {
 parameter_types! {
     pub const AccumulationPeriod: BlockNumberFor<Test> = BLOCKS_PER_HOUR * 24;
     pub const FaucetAmount: Balance = 1000;
+    pub const MaxEmissionPerPeriod: Balance = 5000;
+    pub const DefaultPowDifficulty: u32 = 8;
+    pub const PowValidity: BlockNumberFor<Test> = 5;
     pub const FaucetPalletId: PalletId = PalletId(*b"pa/facet");
 }
 
 impl pallet_faucet::Config for Test {
     type AccumulationPeriod = AccumulationPeriod;
     type FaucetAmount = FaucetAmount;
+    type MaxEmissionPerPeriod = MaxEmissionPerPeriod;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type DefaultPowDifficulty = DefaultPowDifficulty;
+    type PowValidity = PowValidity;
+    type AttestationSignature = TestSignature;
+    type AttestationSigner = UintAuthorityId;
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type PalletId = FaucetPalletId;
     type WeightInfo = ();
 }
 
-#[derive(Default)]
-pub struct ExtBuilder {}
+pub struct ExtBuilder {
+    pot: Balance,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        Self { pot: 1_000_000 }
+    }
+}
 
 impl ExtBuilder {
+    pub fn pot(mut self, pot: Balance) -> Self {
+        self.pot = pot;
+        self
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
-        let storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+        let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+        pallet_balances::GenesisConfig::<Test> { balances: vec![(Faucet::account_id(), self.pot)] }
+            .assimilate_storage(&mut storage)
+            .unwrap();
 
         sp_io::TestExternalities::from(storage)
     }
@@ -1,5 +1,20 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+    migrations, mock::*, AccumulationPeriod, Attestation, Call, Error, Event, FaucetAmount,
+    GenesisConfig, NoncePruneCursor, PruneCursor, Requests, Tier, UsedNonces, WeightInfo,
+    MAX_POW_DIFFICULTY,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    pallet_prelude::ValidateUnsigned,
+    traits::{GetStorageVersion, OnIdle, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{
+    testing::TestSignature,
+    transaction_validity::{InvalidTransaction, TransactionSource},
+    BuildStorage,
+};
 
 #[test]
 fn faucet_works() {
@@ -7,7 +22,7 @@ fn faucet_works() {
         let balance = 200;
         let receiver = 1;
         assert_eq!(Balances::free_balance(receiver), 0);
-        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, balance));
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, balance, 0, 0));
         assert_eq!(Balances::free_balance(receiver), balance);
     })
 }
@@ -17,7 +32,7 @@ fn faucet_fail_send_more_than_max() {
     ExtBuilder::default().build_and_execute(|| {
         let balance = 1000 + 5;
         assert_noop!(
-            Faucet::request_funds(RuntimeOrigin::none(), 1, balance),
+            Faucet::request_funds(RuntimeOrigin::none(), 1, balance, 0, 0),
             Error::<Test>::AmountTooHigh
         );
     });
@@ -27,36 +42,741 @@ fn faucet_fail_send_more_than_max() {
 fn faucet_fail_exceed_max_amount_during_period() {
     ExtBuilder::default().build_and_execute(|| {
         let receiver = 1;
-        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 100));
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 100, 0, 0));
         assert_eq!(Balances::free_balance(1), 100);
 
         System::set_block_number(BLOCKS_PER_HOUR * 7);
 
-        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 200));
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 200, 0, 0));
         assert_eq!(Balances::free_balance(1), 300);
 
         System::set_block_number(BLOCKS_PER_HOUR * 20);
 
-        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 500));
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 500, 0, 0));
         assert_eq!(Balances::free_balance(1), 800);
 
         System::set_block_number(BLOCKS_PER_HOUR * 23);
 
         assert_noop!(
-            Faucet::request_funds(RuntimeOrigin::none(), receiver, 210),
+            Faucet::request_funds(RuntimeOrigin::none(), receiver, 210, 0, 0),
             Error::<Test>::RequestLimitExceeded
         );
 
         System::set_block_number(BLOCKS_PER_HOUR * 24 - 1);
 
         assert_noop!(
-            Faucet::request_funds(RuntimeOrigin::none(), receiver, 210),
+            Faucet::request_funds(RuntimeOrigin::none(), receiver, 210, 0, 0),
             Error::<Test>::RequestLimitExceeded
         );
 
         System::set_block_number(BLOCKS_PER_HOUR * 24);
 
-        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 210));
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 210, 0, 0));
         assert_eq!(Balances::free_balance(1), 1010);
     });
 }
+
+#[test]
+fn faucet_sends_funds_from_pot() {
+    ExtBuilder::default().build_and_execute(|| {
+        let pot = Balances::free_balance(Faucet::account_id());
+        let issuance = Balances::total_issuance();
+
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 1, 500, 0, 0));
+
+        assert_eq!(Balances::free_balance(Faucet::account_id()), pot - 500);
+        assert_eq!(Balances::total_issuance(), issuance);
+    });
+}
+
+#[test]
+fn faucet_fail_when_drained() {
+    ExtBuilder::default().pot(300).build_and_execute(|| {
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 1, 200, 0, 0));
+
+        // the faucet account must stay alive
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), 2, 100, 0, 0),
+            Error::<Test>::FaucetDrained
+        );
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), 2, 200, 0, 0),
+            Error::<Test>::FaucetDrained
+        );
+    });
+}
+
+#[test]
+fn faucet_fail_exceed_max_emission_during_period() {
+    ExtBuilder::default().build_and_execute(|| {
+        for receiver in 1..=5 {
+            assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 1000, 0, 0));
+        }
+
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), 6, 1, 0, 0),
+            Error::<Test>::EmissionLimitExceeded
+        );
+
+        System::set_block_number(BLOCKS_PER_HOUR * 24);
+
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 6, 1000, 0, 0));
+        assert_eq!(Faucet::emission(), (1000, BLOCKS_PER_HOUR * 24));
+    });
+}
+
+#[test]
+fn faucet_reports_low_pot() {
+    ExtBuilder::default().pot(1500).build_and_execute(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 1, 400, 0, 0));
+        assert!(!System::events()
+            .iter()
+            .any(|record| matches!(record.event, RuntimeEvent::Faucet(Event::PotLow { .. }))));
+
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 1, 300, 0, 0));
+        System::assert_last_event(Event::PotLow { balance: 800 }.into());
+    });
+}
+
+fn validate(call: &Call<Test>) -> sp_runtime::transaction_validity::TransactionValidity {
+    Faucet::validate_unsigned(TransactionSource::External, call)
+}
+
+#[test]
+fn faucet_accepts_valid_pow() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::initialize(&10, &H256::repeat_byte(1), &Default::default());
+
+        let (who, amount, block_number) = (1, 100, 9);
+        let block_hash = System::block_hash(block_number);
+        let nonce = Faucet::solve_pow(&block_hash, &who, amount, Faucet::pow_difficulty());
+
+        assert_ok!(validate(&Call::request_funds { who, amount, block_number, nonce }));
+    });
+}
+
+#[test]
+fn faucet_rejects_invalid_pow() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::initialize(&10, &H256::repeat_byte(1), &Default::default());
+
+        let (who, amount, block_number) = (1, 100, 9);
+        let block_hash = System::block_hash(block_number);
+        let nonce = (0..)
+            .find(|nonce| {
+                Faucet::pow_work(&block_hash, &who, amount, *nonce) < Faucet::pow_difficulty()
+            })
+            .unwrap();
+
+        assert_eq!(
+            validate(&Call::request_funds { who, amount, block_number, nonce }),
+            InvalidTransaction::BadProof.into()
+        );
+        // the block must be in the past
+        assert_eq!(
+            validate(&Call::request_funds { who, amount, block_number: 10, nonce }),
+            InvalidTransaction::Future.into()
+        );
+
+        System::initialize(&(10 + PowValidity::get()), &H256::repeat_byte(2), &Default::default());
+
+        // the block must be recent
+        assert_eq!(
+            validate(&Call::request_funds { who, amount, block_number, nonce }),
+            InvalidTransaction::Stale.into()
+        );
+    });
+}
+
+#[test]
+fn faucet_rejects_unsigned_requests_over_limits() {
+    ExtBuilder::default().pot(4500).build_and_execute(|| {
+        System::set_block_number(1);
+
+        assert_eq!(
+            validate(&Call::request_funds { who: 1, amount: 1001, block_number: 0, nonce: 0 }),
+            InvalidTransaction::Call.into()
+        );
+
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 1, 1000, 0, 0));
+        // over the account's quota
+        assert_eq!(
+            validate(&Call::request_funds { who: 1, amount: 1, block_number: 0, nonce: 0 }),
+            InvalidTransaction::ExhaustsResources.into()
+        );
+        assert_eq!(
+            validate(&attested_call(1, 1, 20, 7, 42)),
+            InvalidTransaction::ExhaustsResources.into()
+        );
+
+        for who in 2..=4 {
+            assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), who, 1000, 0, 0));
+        }
+        // over the pot balance
+        assert_eq!(
+            validate(&Call::request_funds { who: 5, amount: 1000, block_number: 0, nonce: 0 }),
+            InvalidTransaction::ExhaustsResources.into()
+        );
+
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(1),
+            Faucet::account_id(),
+            1000
+        ));
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 5, 1000, 0, 0));
+        // over the period emission
+        assert_eq!(
+            validate(&Call::request_funds { who: 6, amount: 1, block_number: 0, nonce: 0 }),
+            InvalidTransaction::ExhaustsResources.into()
+        );
+    });
+}
+
+#[test]
+fn set_pow_difficulty_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(1);
+        assert_eq!(Faucet::pow_difficulty(), DefaultPowDifficulty::get());
+
+        assert_noop!(
+            Faucet::set_pow_difficulty(RuntimeOrigin::signed(1), 16),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Faucet::set_pow_difficulty(RuntimeOrigin::root(), 16));
+        assert_eq!(Faucet::pow_difficulty(), 16);
+        System::assert_last_event(Event::PowDifficultySet { difficulty: 16 }.into());
+    });
+}
+
+#[test]
+fn set_pow_difficulty_rejects_too_high_difficulty() {
+    ExtBuilder::default().build_and_execute(|| {
+        assert_noop!(
+            Faucet::set_pow_difficulty(RuntimeOrigin::root(), MAX_POW_DIFFICULTY + 1),
+            Error::<Test>::DifficultyTooHigh
+        );
+        assert_noop!(
+            Faucet::set_pow_difficulty(RuntimeOrigin::root(), 257),
+            Error::<Test>::DifficultyTooHigh
+        );
+
+        assert_ok!(Faucet::set_pow_difficulty(RuntimeOrigin::root(), MAX_POW_DIFFICULTY));
+        assert_eq!(Faucet::pow_difficulty(), MAX_POW_DIFFICULTY);
+    });
+}
+
+fn attested_call(
+    who: AccountId,
+    amount: Balance,
+    expiry: u64,
+    nonce: u64,
+    attester: AccountId,
+) -> Call<Test> {
+    let payload = Faucet::attestation_payload(&who, amount, expiry, nonce);
+    let signature = TestSignature(attester, payload);
+    Call::request_funds_attested {
+        who,
+        amount,
+        attestation: Attestation { expiry, nonce, attester, signature },
+    }
+}
+
+#[test]
+fn faucet_accepts_valid_attestation() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(10);
+        let (who, amount, attester) = (1, 100, 42);
+        assert_ok!(Faucet::add_attester(RuntimeOrigin::root(), attester));
+
+        let call = attested_call(who, amount, 20, 7, attester);
+        assert_ok!(validate(&call));
+
+        let Call::request_funds_attested { attestation, .. } = call.clone() else { unreachable!() };
+        assert_ok!(Faucet::request_funds_attested(RuntimeOrigin::none(), who, amount, attestation));
+        assert_eq!(Balances::free_balance(who), amount);
+        assert_eq!(UsedNonces::<Test>::get(attester, 7), Some(20));
+
+        // the attestation can't be replayed
+        assert_eq!(validate(&call), InvalidTransaction::Stale.into());
+    });
+}
+
+#[test]
+fn faucet_rejects_invalid_attestation() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(10);
+        let (who, amount, attester) = (1, 100, 42);
+
+        assert_eq!(
+            validate(&attested_call(who, amount, 20, 7, attester)),
+            InvalidTransaction::BadSigner.into()
+        );
+
+        assert_ok!(Faucet::add_attester(RuntimeOrigin::root(), attester));
+
+        // signed for another amount
+        let Call::request_funds_attested { attestation, .. } =
+            attested_call(who, amount + 1, 20, 7, attester)
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            validate(&Call::request_funds_attested { who, amount, attestation }),
+            InvalidTransaction::BadProof.into()
+        );
+
+        // signed by another account
+        let Call::request_funds_attested { attestation, .. } =
+            attested_call(who, amount, 20, 7, attester + 1)
+        else {
+            unreachable!()
+        };
+        let attestation = Attestation { attester, ..attestation };
+        assert_eq!(
+            validate(&Call::request_funds_attested { who, amount, attestation }),
+            InvalidTransaction::BadProof.into()
+        );
+
+        // expired
+        assert_eq!(
+            validate(&attested_call(who, amount, 9, 7, attester)),
+            InvalidTransaction::Stale.into()
+        );
+
+        // signed for another chain
+        let call = attested_call(who, amount, 20, 7, attester);
+        frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+        assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+    });
+}
+
+#[test]
+fn manage_attesters_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(1);
+        let attester = 42;
+
+        assert_noop!(
+            Faucet::add_attester(RuntimeOrigin::signed(1), attester),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Faucet::remove_attester(RuntimeOrigin::root(), attester),
+            Error::<Test>::NotAttester
+        );
+
+        assert_ok!(Faucet::add_attester(RuntimeOrigin::root(), attester));
+        System::assert_last_event(Event::AttesterAdded { attester }.into());
+        assert_noop!(
+            Faucet::add_attester(RuntimeOrigin::root(), attester),
+            Error::<Test>::AlreadyAttester
+        );
+
+        UsedNonces::<Test>::insert(attester, 7, 20);
+        UsedNonces::<Test>::insert(attester, 8, 20);
+        UsedNonces::<Test>::insert(attester + 1, 7, 20);
+
+        assert_ok!(Faucet::remove_attester(RuntimeOrigin::root(), attester));
+        System::assert_last_event(Event::AttesterRemoved { attester }.into());
+        assert_eq!(UsedNonces::<Test>::iter_prefix(attester).count(), 2);
+        assert_eq!(
+            validate(&attested_call(1, 100, 20, 9, attester)),
+            InvalidTransaction::BadSigner.into()
+        );
+
+        // the used nonces of a removed attester can't be replayed once it's added back
+        assert_ok!(Faucet::add_attester(RuntimeOrigin::root(), attester));
+        assert_eq!(
+            validate(&attested_call(1, 100, 20, 7, attester)),
+            InvalidTransaction::Stale.into()
+        );
+    });
+}
+
+#[test]
+fn faucet_signed_request_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        let receiver = 1;
+        assert_noop!(
+            Faucet::request_funds_signed(RuntimeOrigin::none(), 100),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(Faucet::request_funds_signed(RuntimeOrigin::signed(receiver), 600));
+        assert_eq!(Balances::free_balance(receiver), 600);
+
+        // signed and unsigned requests share the limit
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), receiver, 500, 0, 0),
+            Error::<Test>::RequestLimitExceeded
+        );
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 400, 0, 0));
+        assert_noop!(
+            Faucet::request_funds_signed(RuntimeOrigin::signed(receiver), 1),
+            Error::<Test>::RequestLimitExceeded
+        );
+    });
+}
+
+#[test]
+fn set_parameters_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(1);
+        assert_eq!(Faucet::faucet_amount(), 1000);
+        assert_eq!(Faucet::accumulation_period(), BLOCKS_PER_HOUR * 24);
+
+        assert_noop!(
+            Faucet::set_parameters(RuntimeOrigin::signed(1), 100, 10),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Faucet::set_parameters(RuntimeOrigin::root(), 100, 0),
+            Error::<Test>::InvalidPeriod
+        );
+
+        assert_ok!(Faucet::set_parameters(RuntimeOrigin::root(), 100, 10));
+        assert_eq!(Faucet::faucet_amount(), 100);
+        assert_eq!(Faucet::accumulation_period(), 10);
+        System::assert_last_event(Event::ParametersUpdated { amount: 100, period: 10 }.into());
+
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), 1, 101, 0, 0),
+            Error::<Test>::AmountTooHigh
+        );
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 1, 100, 0, 0));
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), 1, 1, 0, 0),
+            Error::<Test>::RequestLimitExceeded
+        );
+
+        System::set_block_number(11);
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 1, 100, 0, 0));
+        assert_eq!(Balances::free_balance(1), 200);
+    });
+}
+
+#[test]
+fn genesis_sets_parameters() {
+    let storage = RuntimeGenesisConfig {
+        faucet: GenesisConfig { faucet_amount: Some(500), ..Default::default() },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap();
+
+    sp_io::TestExternalities::from(storage).execute_with(|| {
+        assert_eq!(Faucet::faucet_amount(), 500);
+        assert_eq!(Faucet::accumulation_period(), BLOCKS_PER_HOUR * 24);
+    });
+}
+
+#[test]
+fn genesis_sets_up_faucet() {
+    let storage = RuntimeGenesisConfig {
+        faucet: GenesisConfig {
+            pot: 10_000,
+            tiers: vec![(1, 5000, 10)],
+            allowlist: vec![(2, 1)],
+            paused: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+    .build_storage()
+    .unwrap();
+
+    sp_io::TestExternalities::from(storage).execute_with(|| {
+        System::set_block_number(1);
+        assert_eq!(Faucet::pot_balance(), 10_000);
+        assert_eq!(Faucet::tiers(1), Some(Tier { amount: 5000, period: 10 }));
+        assert_eq!(Faucet::account_tier(2), Some(1));
+        assert!(Faucet::paused());
+
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), 2, 2000, 0, 0),
+            Error::<Test>::FaucetPaused
+        );
+        assert_eq!(
+            validate(&Call::request_funds { who: 2, amount: 2000, block_number: 0, nonce: 0 }),
+            InvalidTransaction::Call.into()
+        );
+        assert_eq!(Faucet::remaining_quota(&2), 0);
+
+        assert_noop!(
+            Faucet::set_paused(RuntimeOrigin::signed(1), false),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Faucet::set_paused(RuntimeOrigin::root(), false));
+        System::assert_last_event(Event::PauseSet { paused: false }.into());
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), 2, 2000, 0, 0));
+        assert_eq!(Faucet::pot_balance(), 8_000);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Allowlisted account has an unknown tier")]
+fn genesis_rejects_unknown_tier() {
+    let _ = RuntimeGenesisConfig {
+        faucet: GenesisConfig { allowlist: vec![(2, 1)], ..Default::default() },
+        ..Default::default()
+    }
+    .build_storage();
+}
+
+#[test]
+#[should_panic(expected = "Accumulation period is zero")]
+fn genesis_rejects_zero_period() {
+    let _ = RuntimeGenesisConfig {
+        faucet: GenesisConfig { accumulation_period: Some(0), ..Default::default() },
+        ..Default::default()
+    }
+    .build_storage();
+}
+
+#[test]
+#[should_panic(expected = "Tier has a zero period")]
+fn genesis_rejects_zero_tier_period() {
+    let _ = RuntimeGenesisConfig {
+        faucet: GenesisConfig { tiers: vec![(1, 5000, 0)], ..Default::default() },
+        ..Default::default()
+    }
+    .build_storage();
+}
+
+#[test]
+fn migration_to_v1_stores_parameters() {
+    ExtBuilder::default().build_and_execute(|| {
+        StorageVersion::new(0).put::<Faucet>();
+        FaucetAmount::<Test>::kill();
+        AccumulationPeriod::<Test>::kill();
+
+        migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Faucet::on_chain_storage_version(), 1);
+        assert_eq!(FaucetAmount::<Test>::get(), 1000);
+        assert!(FaucetAmount::<Test>::exists());
+        assert!(AccumulationPeriod::<Test>::exists());
+
+        // running it again doesn't override the updated parameters
+        assert_ok!(Faucet::set_parameters(RuntimeOrigin::root(), 100, 10));
+        migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Faucet::faucet_amount(), 100);
+    });
+}
+
+#[test]
+fn tiers_override_default_limits() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(1);
+        let partner = 1;
+        let tier = Tier { amount: 3000, period: 10 };
+
+        assert_noop!(
+            Faucet::set_account_tier(RuntimeOrigin::root(), partner, Some(1)),
+            Error::<Test>::UnknownTier
+        );
+        assert_noop!(
+            Faucet::set_tier(RuntimeOrigin::signed(1), 1, Some(tier)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Faucet::set_tier(RuntimeOrigin::root(), 1, Some(Tier { amount: 3000, period: 0 })),
+            Error::<Test>::InvalidPeriod
+        );
+
+        assert_ok!(Faucet::set_tier(RuntimeOrigin::root(), 1, Some(tier)));
+        System::assert_last_event(Event::TierSet { tier: 1, limits: Some(tier) }.into());
+        assert_ok!(Faucet::set_account_tier(RuntimeOrigin::root(), partner, Some(1)));
+        System::assert_last_event(Event::AccountTierSet { who: partner, tier: Some(1) }.into());
+        assert_eq!(Faucet::limits(&partner), tier);
+
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), partner, 3000, 0, 0));
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), partner, 1, 0, 0),
+            Error::<Test>::RequestLimitExceeded
+        );
+        // other accounts keep the default limits
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), 2, 1001, 0, 0),
+            Error::<Test>::AmountTooHigh
+        );
+
+        // the tier period restarts the accumulation
+        System::set_block_number(11);
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), partner, 1000, 0, 0));
+        assert_eq!(Balances::free_balance(partner), 4000);
+
+        // removing the tier falls back to the default limits
+        assert_ok!(Faucet::set_tier(RuntimeOrigin::root(), 1, None));
+        assert_eq!(Faucet::limits(&partner), Tier { amount: 1000, period: BLOCKS_PER_HOUR * 24 });
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), partner, 1, 0, 0),
+            Error::<Test>::RequestLimitExceeded
+        );
+
+        assert_ok!(Faucet::set_account_tier(RuntimeOrigin::root(), partner, None));
+        assert_eq!(Faucet::account_tier(partner), None);
+    });
+}
+
+#[test]
+fn denylist_works() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(1);
+        let abuser = 1;
+
+        assert_noop!(
+            Faucet::allow_account(RuntimeOrigin::root(), abuser),
+            Error::<Test>::NotDenied
+        );
+        assert_ok!(Faucet::deny_account(RuntimeOrigin::root(), abuser));
+        System::assert_last_event(Event::AccountDenied { who: abuser }.into());
+        assert_noop!(
+            Faucet::deny_account(RuntimeOrigin::root(), abuser),
+            Error::<Test>::AccountDenied
+        );
+
+        assert_noop!(
+            Faucet::request_funds(RuntimeOrigin::none(), abuser, 100, 0, 0),
+            Error::<Test>::AccountDenied
+        );
+        assert_noop!(
+            Faucet::request_funds_signed(RuntimeOrigin::signed(abuser), 100),
+            Error::<Test>::AccountDenied
+        );
+        assert_eq!(
+            validate(&Call::request_funds { who: abuser, amount: 100, block_number: 0, nonce: 0 }),
+            InvalidTransaction::Call.into()
+        );
+
+        assert_ok!(Faucet::allow_account(RuntimeOrigin::root(), abuser));
+        System::assert_last_event(Event::AccountAllowed { who: abuser }.into());
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), abuser, 100, 0, 0));
+    });
+}
+
+#[test]
+fn quota_queries_work() {
+    ExtBuilder::default().pot(10_000).build_and_execute(|| {
+        System::set_block_number(1);
+        let receiver = 1;
+        assert_eq!(Faucet::remaining_quota(&receiver), 1000);
+        assert_eq!(Faucet::next_reset_block(&receiver), None);
+        assert_eq!(Faucet::pot_balance(), 10_000);
+
+        assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), receiver, 300, 0, 0));
+        assert_eq!(Faucet::remaining_quota(&receiver), 700);
+        assert_eq!(Faucet::next_reset_block(&receiver), Some(1 + BLOCKS_PER_HOUR * 24));
+        assert_eq!(Faucet::pot_balance(), 9_700);
+
+        // the global emission limit is shared by all users
+        for who in 2..6 {
+            assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), who, 1000, 0, 0));
+        }
+        assert_eq!(Faucet::remaining_quota(&receiver), 700);
+        assert_eq!(Faucet::remaining_quota(&6), 700);
+
+        System::set_block_number(1 + BLOCKS_PER_HOUR * 24);
+        assert_eq!(Faucet::remaining_quota(&receiver), 1000);
+        assert_eq!(Faucet::next_reset_block(&receiver), None);
+
+        assert_ok!(Faucet::deny_account(RuntimeOrigin::root(), receiver));
+        assert_eq!(Faucet::remaining_quota(&receiver), 0);
+    });
+}
+
+#[test]
+fn on_idle_prunes_expired_requests() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(1);
+        assert_ok!(Faucet::set_tier(
+            RuntimeOrigin::root(),
+            1,
+            Some(Tier { amount: 100, period: 10 })
+        ));
+        assert_ok!(Faucet::set_account_tier(RuntimeOrigin::root(), 3, Some(1)));
+        for who in 1..=3 {
+            assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), who, 100, 0, 0));
+        }
+
+        // only the tier period has passed
+        Faucet::on_idle(11, Weight::MAX);
+        assert!(Requests::<Test>::contains_key(1));
+        assert!(Requests::<Test>::contains_key(2));
+        assert!(!Requests::<Test>::contains_key(3));
+        assert_eq!(PruneCursor::<Test>::get(), None);
+
+        let now = 1 + BLOCKS_PER_HOUR * 24;
+        Faucet::on_idle(now, Weight::MAX);
+        assert_eq!(Requests::<Test>::iter().count(), 0);
+        assert_eq!(Faucet::remaining_quota(&1), 1000);
+    });
+}
+
+#[test]
+fn on_idle_prunes_expired_nonces() {
+    ExtBuilder::default().build_and_execute(|| {
+        UsedNonces::<Test>::insert(42, 1, 10);
+        UsedNonces::<Test>::insert(42, 2, 20);
+        UsedNonces::<Test>::insert(43, 1, 5);
+
+        // an attestation expiring at `now` can still be used
+        Faucet::on_idle(10, Weight::MAX);
+        assert_eq!(UsedNonces::<Test>::get(42, 1), Some(10));
+        assert_eq!(UsedNonces::<Test>::get(42, 2), Some(20));
+        assert_eq!(UsedNonces::<Test>::get(43, 1), None);
+        assert_eq!(NoncePruneCursor::<Test>::get(), None);
+
+        // the requests pruning leaves no weight for the nonces
+        let requests_base = <() as WeightInfo>::on_idle_base();
+        Faucet::on_idle(21, requests_base);
+        assert_eq!(UsedNonces::<Test>::iter().count(), 2);
+
+        let base = <() as WeightInfo>::prune_nonces_base();
+        let per_nonce = <() as WeightInfo>::prune_nonce();
+        let per_request = <() as WeightInfo>::prune_request();
+        assert_eq!(
+            Faucet::on_idle(21, requests_base + per_request + base + per_nonce),
+            requests_base + per_request + base + per_nonce
+        );
+        assert_eq!(UsedNonces::<Test>::iter().count(), 1);
+        assert!(NoncePruneCursor::<Test>::get().is_some());
+
+        Faucet::on_idle(21, Weight::MAX);
+        assert_eq!(UsedNonces::<Test>::iter().count(), 0);
+        assert_eq!(NoncePruneCursor::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn on_idle_prunes_within_remaining_weight() {
+    ExtBuilder::default().build_and_execute(|| {
+        System::set_block_number(1);
+        for who in 1..=3 {
+            assert_ok!(Faucet::request_funds(RuntimeOrigin::none(), who, 100, 0, 0));
+        }
+        let now = 1 + BLOCKS_PER_HOUR * 24;
+        let base = <() as WeightInfo>::on_idle_base();
+        let per_request = <() as WeightInfo>::prune_request();
+
+        assert_eq!(Faucet::on_idle(now, base - Weight::from_parts(1, 0)), Weight::zero());
+        assert_eq!(Requests::<Test>::iter().count(), 3);
+
+        assert_eq!(Faucet::on_idle(now, base + per_request * 2), base + per_request * 2);
+        assert_eq!(Requests::<Test>::iter().count(), 1);
+        let cursor = PruneCursor::<Test>::get();
+        assert!(cursor.is_some());
+
+        // no weight left to visit an entry keeps the cursor
+        assert_eq!(Faucet::on_idle(now, base), base);
+        assert_eq!(PruneCursor::<Test>::get(), cursor);
+
+        // resumes after the cursor and restarts once all entries are visited
+        Faucet::on_idle(now, base + per_request * 2);
+        assert_eq!(Requests::<Test>::iter().count(), 0);
+        assert_eq!(PruneCursor::<Test>::get(), None);
+    });
+}
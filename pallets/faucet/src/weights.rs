@@ -1,29 +1,13 @@
-//! Autogenerated weights for `pallet_faucet`
+//! Weights for `pallet_faucet`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-06-12, STEPS: `20`, REPEAT: `10`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `admin`, CPU: `<UNKNOWN>`
-//! EXECUTION: None, WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ./target/release/gafi-node
-// benchmark
-// pallet
-// --chain
-// dev
-// --wasm-execution
-// compiled
-// --pallet
-// pallet_faucet
-// --extrinsic
-// *
-// --steps
-// 20
-// --repeat
-// 10
-// --output
-// ./benchmarking/pallet-faucet/weights.rs
+//! Derived from the storage accessed by the benchmarks in `benchmarking.rs`, the execution time
+//! is an estimate. Regenerate on the reference hardware with:
+//!
+//! ./target/release/atleta-node benchmark pallet \
+//!     --chain dev --wasm-execution compiled \
+//!     --pallet pallet_faucet --extrinsic '*' \
+//!     --steps 50 --repeat 20 \
+//!     --output ./pallets/faucet/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -36,19 +20,285 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
     #[allow(missing_docs)]
     fn request_funds() -> Weight;
+    #[allow(missing_docs)]
+    fn set_pow_difficulty() -> Weight;
+    #[allow(missing_docs)]
+    fn request_funds_attested() -> Weight;
+    #[allow(missing_docs)]
+    fn add_attester() -> Weight;
+    #[allow(missing_docs)]
+    fn remove_attester() -> Weight;
+    #[allow(missing_docs)]
+    fn request_funds_signed() -> Weight;
+    #[allow(missing_docs)]
+    fn set_parameters() -> Weight;
+    #[allow(missing_docs)]
+    fn set_tier() -> Weight;
+    #[allow(missing_docs)]
+    fn set_account_tier() -> Weight;
+    #[allow(missing_docs)]
+    fn deny_account() -> Weight;
+    #[allow(missing_docs)]
+    fn allow_account() -> Weight;
+    #[allow(missing_docs)]
+    fn on_idle_base() -> Weight;
+    #[allow(missing_docs)]
+    fn prune_request() -> Weight;
+    #[allow(missing_docs)]
+    fn set_paused() -> Weight;
+    #[allow(missing_docs)]
+    fn prune_nonces_base() -> Weight;
+    #[allow(missing_docs)]
+    fn prune_nonce() -> Weight;
 }
 
 /// Weights for pallet_faucet using the Substrate node and recommended hardware.
 pub struct FaucetWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for FaucetWeight<T> {
+    /// Storage: `Faucet::Paused` (r:1 w:0)
+    /// Storage: `Faucet::Denylist` (r:1 w:0)
+    /// Storage: `Faucet::AccountTier` (r:1 w:0)
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    /// Storage: `Faucet::Requests` (r:1 w:1)
+    /// Storage: `Faucet::Emission` (r:1 w:1)
+    /// Storage: `Faucet::AccumulationPeriod` (r:1 w:0)
+    /// Storage: `Faucet::FaucetAmount` (r:1 w:0)
+    /// Storage: `System::Account` (r:2 w:2)
     fn request_funds() -> Weight {
-        Weight::zero()
+        Weight::from_parts(62_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Faucet::PowDifficulty` (r:0 w:1)
+    fn set_pow_difficulty() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Paused` (r:1 w:0)
+    /// Storage: `Faucet::Denylist` (r:1 w:0)
+    /// Storage: `Faucet::AccountTier` (r:1 w:0)
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    /// Storage: `Faucet::Requests` (r:1 w:1)
+    /// Storage: `Faucet::Emission` (r:1 w:1)
+    /// Storage: `Faucet::AccumulationPeriod` (r:1 w:0)
+    /// Storage: `Faucet::FaucetAmount` (r:1 w:0)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Faucet::UsedNonces` (r:0 w:1)
+    fn request_funds_attested() -> Weight {
+        Weight::from_parts(66_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Faucet::Attesters` (r:1 w:1)
+    fn add_attester() -> Weight {
+        Weight::from_parts(11_000_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Attesters` (r:1 w:1)
+    fn remove_attester() -> Weight {
+        Weight::from_parts(11_500_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Paused` (r:1 w:0)
+    /// Storage: `Faucet::Denylist` (r:1 w:0)
+    /// Storage: `Faucet::AccountTier` (r:1 w:0)
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    /// Storage: `Faucet::Requests` (r:1 w:1)
+    /// Storage: `Faucet::Emission` (r:1 w:1)
+    /// Storage: `Faucet::AccumulationPeriod` (r:1 w:0)
+    /// Storage: `Faucet::FaucetAmount` (r:1 w:0)
+    /// Storage: `System::Account` (r:2 w:2)
+    fn request_funds_signed() -> Weight {
+        Weight::from_parts(62_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Faucet::FaucetAmount` (r:0 w:1)
+    /// Storage: `Faucet::AccumulationPeriod` (r:0 w:1)
+    fn set_parameters() -> Weight {
+        Weight::from_parts(9_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Faucet::Tiers` (r:0 w:1)
+    fn set_tier() -> Weight {
+        Weight::from_parts(8_500_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    /// Storage: `Faucet::AccountTier` (r:0 w:1)
+    fn set_account_tier() -> Weight {
+        Weight::from_parts(12_000_000, 3499)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Denylist` (r:1 w:1)
+    fn deny_account() -> Weight {
+        Weight::from_parts(11_000_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Denylist` (r:1 w:1)
+    fn allow_account() -> Weight {
+        Weight::from_parts(11_500_000, 3513)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::PruneCursor` (r:1 w:1)
+    fn on_idle_base() -> Weight {
+        Weight::from_parts(4_000_000, 1505)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Requests` (r:1 w:1)
+    /// Storage: `Faucet::AccountTier` (r:1 w:0)
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    fn prune_request() -> Weight {
+        Weight::from_parts(15_000_000, 3537)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Paused` (r:0 w:1)
+    fn set_paused() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::NoncePruneCursor` (r:1 w:1)
+    fn prune_nonces_base() -> Weight {
+        Weight::from_parts(4_000_000, 1505)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::UsedNonces` (r:1 w:1)
+    fn prune_nonce() -> Weight {
+        Weight::from_parts(9_000_000, 3529)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
     }
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+    /// Storage: `Faucet::Paused` (r:1 w:0)
+    /// Storage: `Faucet::Denylist` (r:1 w:0)
+    /// Storage: `Faucet::AccountTier` (r:1 w:0)
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    /// Storage: `Faucet::Requests` (r:1 w:1)
+    /// Storage: `Faucet::Emission` (r:1 w:1)
+    /// Storage: `Faucet::AccumulationPeriod` (r:1 w:0)
+    /// Storage: `Faucet::FaucetAmount` (r:1 w:0)
+    /// Storage: `System::Account` (r:2 w:2)
     fn request_funds() -> Weight {
-        Weight::zero()
+        Weight::from_parts(62_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Faucet::PowDifficulty` (r:0 w:1)
+    fn set_pow_difficulty() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Paused` (r:1 w:0)
+    /// Storage: `Faucet::Denylist` (r:1 w:0)
+    /// Storage: `Faucet::AccountTier` (r:1 w:0)
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    /// Storage: `Faucet::Requests` (r:1 w:1)
+    /// Storage: `Faucet::Emission` (r:1 w:1)
+    /// Storage: `Faucet::AccumulationPeriod` (r:1 w:0)
+    /// Storage: `Faucet::FaucetAmount` (r:1 w:0)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Storage: `Faucet::UsedNonces` (r:0 w:1)
+    fn request_funds_attested() -> Weight {
+        Weight::from_parts(66_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    /// Storage: `Faucet::Attesters` (r:1 w:1)
+    fn add_attester() -> Weight {
+        Weight::from_parts(11_000_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Attesters` (r:1 w:1)
+    fn remove_attester() -> Weight {
+        Weight::from_parts(11_500_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Paused` (r:1 w:0)
+    /// Storage: `Faucet::Denylist` (r:1 w:0)
+    /// Storage: `Faucet::AccountTier` (r:1 w:0)
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    /// Storage: `Faucet::Requests` (r:1 w:1)
+    /// Storage: `Faucet::Emission` (r:1 w:1)
+    /// Storage: `Faucet::AccumulationPeriod` (r:1 w:0)
+    /// Storage: `Faucet::FaucetAmount` (r:1 w:0)
+    /// Storage: `System::Account` (r:2 w:2)
+    fn request_funds_signed() -> Weight {
+        Weight::from_parts(62_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: `Faucet::FaucetAmount` (r:0 w:1)
+    /// Storage: `Faucet::AccumulationPeriod` (r:0 w:1)
+    fn set_parameters() -> Weight {
+        Weight::from_parts(9_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: `Faucet::Tiers` (r:0 w:1)
+    fn set_tier() -> Weight {
+        Weight::from_parts(8_500_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    /// Storage: `Faucet::AccountTier` (r:0 w:1)
+    fn set_account_tier() -> Weight {
+        Weight::from_parts(12_000_000, 3499)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Denylist` (r:1 w:1)
+    fn deny_account() -> Weight {
+        Weight::from_parts(11_000_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Denylist` (r:1 w:1)
+    fn allow_account() -> Weight {
+        Weight::from_parts(11_500_000, 3513)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::PruneCursor` (r:1 w:1)
+    fn on_idle_base() -> Weight {
+        Weight::from_parts(4_000_000, 1505)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Requests` (r:1 w:1)
+    /// Storage: `Faucet::AccountTier` (r:1 w:0)
+    /// Storage: `Faucet::Tiers` (r:1 w:0)
+    fn prune_request() -> Weight {
+        Weight::from_parts(15_000_000, 3537)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::Paused` (r:0 w:1)
+    fn set_paused() -> Weight {
+        Weight::from_parts(8_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::NoncePruneCursor` (r:1 w:1)
+    fn prune_nonces_base() -> Weight {
+        Weight::from_parts(4_000_000, 1505)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `Faucet::UsedNonces` (r:1 w:1)
+    fn prune_nonce() -> Weight {
+        Weight::from_parts(9_000_000, 3529)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
     }
 }
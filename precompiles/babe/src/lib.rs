@@ -2,9 +2,22 @@
 #![allow(missing_docs)]
 
 use fp_evm::PrecompileHandle;
+use frame_support::traits::{IsType, Randomness};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_babe::{AuthorityId, RandomnessFromOneEpochAgo};
+use pallet_evm::PrecompileFailure;
+use parity_scale_codec::MaxEncodedLen;
 use precompile_utils::prelude::*;
-use sp_core::Get;
-use sp_std::marker::PhantomData;
+use sp_consensus_babe::{BabeAuthorityWeight, BabeEpochConfiguration, RANDOMNESS_LENGTH};
+use sp_core::{Get, H256};
+use sp_runtime::{SaturatedConversion, WeakBoundedVec};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
 pub struct BabePrecompile<Runtime>(PhantomData<Runtime>);
 
@@ -13,6 +26,7 @@ impl<Runtime> BabePrecompile<Runtime>
 where
     Runtime: pallet_evm::Config + pallet_babe::Config,
     Runtime::Moment: Into<u64>,
+    Runtime::Hash: IsType<H256>,
 {
     #[precompile::public("epochDuration()")]
     #[precompile::view]
@@ -25,4 +39,105 @@ where
     fn expected_block_time(_: &mut impl PrecompileHandle) -> EvmResult<u64> {
         Ok(<Runtime as pallet_babe::Config>::ExpectedBlockTime::get().into())
     }
+
+    #[precompile::public("currentSlot()")]
+    #[precompile::view]
+    fn current_slot(h: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        // Storage item: CurrentSlot: Slot
+        h.record_db_read::<Runtime>(u64::max_encoded_len())?;
+        Ok(pallet_babe::Pallet::<Runtime>::current_slot().into())
+    }
+
+    #[precompile::public("epochIndex()")]
+    #[precompile::view]
+    fn epoch_index(h: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        // Storage item: EpochIndex: u64
+        h.record_db_read::<Runtime>(u64::max_encoded_len())?;
+        Ok(pallet_babe::Pallet::<Runtime>::epoch_index())
+    }
+
+    /// Returns the first slot of the current epoch.
+    #[precompile::public("currentEpochStart()")]
+    #[precompile::view]
+    fn current_epoch_start(h: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        // Storage items: GenesisSlot: Slot, EpochIndex: u64
+        h.record_db_read::<Runtime>(u64::max_encoded_len())?;
+        h.record_db_read::<Runtime>(u64::max_encoded_len())?;
+        Ok(pallet_babe::Pallet::<Runtime>::current_epoch_start().into())
+    }
+
+    /// Returns the slot of the first block, or 0 before it's produced.
+    #[precompile::public("genesisSlot()")]
+    #[precompile::view]
+    fn genesis_slot(h: &mut impl PrecompileHandle) -> EvmResult<u64> {
+        // Storage item: GenesisSlot: Slot
+        h.record_db_read::<Runtime>(u64::max_encoded_len())?;
+        Ok(pallet_babe::Pallet::<Runtime>::genesis_slot().into())
+    }
+
+    /// Returns the sr25519 public keys of the current epoch authorities with their weights.
+    #[precompile::public("authorities()")]
+    #[precompile::view]
+    fn authorities(h: &mut impl PrecompileHandle) -> EvmResult<Vec<(H256, u64)>> {
+        // Storage item: Authorities: WeakBoundedVec<(AuthorityId, Weight), MaxAuthorities>
+        h.record_db_read::<Runtime>(Self::authorities_len())?;
+        Ok(Self::authorities_to_output(pallet_babe::Pallet::<Runtime>::authorities().into_inner()))
+    }
+
+    /// Returns the sr25519 public keys of the next epoch authorities with their weights.
+    #[precompile::public("nextEpochAuthorities()")]
+    #[precompile::view]
+    fn next_epoch_authorities(h: &mut impl PrecompileHandle) -> EvmResult<Vec<(H256, u64)>> {
+        // Storage item: EpochConfig: BabeEpochConfiguration
+        h.record_db_read::<Runtime>(BabeEpochConfiguration::max_encoded_len())?;
+        // `next_epoch` expects the epoch config to be set, which it is from genesis on
+        pallet_babe::Pallet::<Runtime>::epoch_config()
+            .ok_or_else(|| Self::custom_err("Unable to get epoch config"))?;
+
+        // Storage items: EpochIndex: u64, GenesisSlot: Slot, NextRandomness: BabeRandomness,
+        // NextEpochConfig: BabeEpochConfiguration and NextAuthorities
+        h.record_db_read::<Runtime>(u64::max_encoded_len())?;
+        h.record_db_read::<Runtime>(u64::max_encoded_len())?;
+        h.record_db_read::<Runtime>(RANDOMNESS_LENGTH)?;
+        h.record_db_read::<Runtime>(BabeEpochConfiguration::max_encoded_len())?;
+        h.record_db_read::<Runtime>(Self::authorities_len())?;
+        Ok(Self::authorities_to_output(pallet_babe::Pallet::<Runtime>::next_epoch().authorities))
+    }
+
+    /// Returns BABE's randomness from one epoch ago and the block number from which this
+    /// randomness is known; commitments must be made before it.
+    ///
+    /// The seed is derived from the VRF outputs of the blocks of the previous epoch, so block
+    /// producers of that epoch could bias it by withholding blocks, and everyone can read it
+    /// during the whole current epoch. It must not be used where an outcome has to stay
+    /// unpredictable, e.g. for lotteries with stakes worth manipulating. Contracts should only
+    /// accept seeds whose returned block number is later than the block of their commitment.
+    #[precompile::public("randomness()")]
+    #[precompile::view]
+    fn randomness(h: &mut impl PrecompileHandle) -> EvmResult<(H256, u32)> {
+        // Storage items: NextRandomness: BabeRandomness, EpochStart: (BlockNumber, BlockNumber)
+        h.record_db_read::<Runtime>(RANDOMNESS_LENGTH)?;
+        h.record_db_read::<Runtime>(2 * BlockNumberFor::<Runtime>::max_encoded_len())?;
+        let (seed, block) = RandomnessFromOneEpochAgo::<Runtime>::random_seed();
+        Ok((seed.into(), block.saturated_into()))
+    }
+
+    /// The encoded length of an authorities list, bounding both `Authorities` and
+    /// `NextAuthorities`.
+    fn authorities_len() -> usize {
+        WeakBoundedVec::<(AuthorityId, BabeAuthorityWeight), Runtime::MaxAuthorities>::max_encoded_len()
+    }
+
+    fn authorities_to_output(
+        authorities: Vec<(AuthorityId, BabeAuthorityWeight)>,
+    ) -> Vec<(H256, u64)> {
+        authorities
+            .into_iter()
+            .map(|(authority, weight)| (H256::from_slice(authority.as_ref()), weight))
+            .collect()
+    }
+
+    fn custom_err(reason: &'static str) -> PrecompileFailure {
+        PrecompileFailure::Error { exit_status: evm::ExitError::Other(reason.into()) }
+    }
 }
@@ -0,0 +1,42 @@
+use crate::BabePrecompile;
+use frame_support::traits::{ConstU32, ConstU64};
+use sp_consensus_babe::{AllowedSlots, AuthorityId, BabeEpochConfiguration};
+use sp_core::sr25519;
+use sp_runtime::BuildStorage;
+
+pub fn authority(seed: u8) -> AuthorityId {
+    sr25519::Public::from_raw([seed; 32]).into()
+}
+
+precompile_test_utils::mock_runtime! {
+    precompile: BabePrecompile<Runtime>,
+    address: 2007,
+    pallets: {
+        Babe: pallet_babe,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000)],
+    genesis: |storage| {
+        pallet_babe::GenesisConfig::<Runtime> {
+            authorities: vec![(authority(1), 1), (authority(2), 2)],
+            epoch_config: BabeEpochConfiguration {
+                c: (1, 4),
+                allowed_slots: AllowedSlots::PrimaryAndSecondaryVRFSlots,
+            },
+            ..Default::default()
+        }
+        .assimilate_storage(storage)
+        .unwrap();
+    },
+}
+
+impl pallet_babe::Config for Runtime {
+    type EpochDuration = ConstU64<10>;
+    type ExpectedBlockTime = ConstU64<6_000>;
+    type EpochChangeTrigger = pallet_babe::ExternalTrigger;
+    type DisabledValidators = ();
+    type WeightInfo = ();
+    type MaxAuthorities = ConstU32<10>;
+    type MaxNominators = ConstU32<100>;
+    type KeyOwnerProof = sp_session::MembershipProof;
+    type EquivocationReportSystem = ();
+}
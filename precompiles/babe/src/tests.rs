@@ -0,0 +1,66 @@
+use crate::mock::*;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+type PCall = crate::BabePrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+#[test]
+fn slot_and_epoch_views_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::current_slot {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(0u64);
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::epoch_index {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(0u64);
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::genesis_slot {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(0u64);
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::current_epoch_start {})
+            .expect_cost(2 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(0u64);
+    });
+}
+
+#[test]
+fn authorities_views_return_keys_with_weights() {
+    ExtBuilder::default().build().execute_with(|| {
+        let expected = vec![(H256::repeat_byte(1), 1u64), (H256::repeat_byte(2), 2u64)];
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::authorities {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(expected.clone());
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::next_epoch_authorities {})
+            .expect_cost(6 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(expected);
+    });
+}
+
+#[test]
+fn randomness_returns_seed_from_one_epoch_ago() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::randomness {})
+            .expect_cost(2 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((BlakeTwo256::hash(&[0u8; 32]), 0u32));
+    });
+}
@@ -0,0 +1,227 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(missing_docs)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::IsType,
+    weights::Weight,
+};
+use pallet_evm::{AddressMapping, PrecompileFailure};
+use parity_scale_codec::{DecodeLimit, Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::{Get, H160, H256};
+use sp_runtime::{
+    traits::{Dispatchable, Hash},
+    BoundedVec,
+};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// The maximum depth of nested calls a proposal may be decoded with.
+const MAX_PROPOSAL_DEPTH: u32 = 8;
+
+/// Exposes an instance of `pallet_collective`, e.g. the council or the technical committee.
+pub struct CollectivePrecompile<Runtime, Instance: 'static>(PhantomData<(Runtime, Instance)>);
+
+type CollectiveProposal<Runtime, Instance> =
+    <Runtime as pallet_collective::Config<Instance>>::Proposal;
+
+#[precompile_utils::precompile]
+impl<Runtime, Instance> CollectivePrecompile<Runtime, Instance>
+where
+    Instance: 'static,
+    Runtime: pallet_evm::Config + pallet_collective::Config<Instance>,
+    Runtime::AccountId: Into<H160>,
+    Runtime::Hash: IsType<H256>,
+    Runtime::RuntimeCall: From<pallet_collective::Call<Runtime, Instance>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+{
+    /// Dispatches the SCALE encoded `proposal` directly as a member of the collective.
+    #[precompile::public("execute(bytes)")]
+    fn execute(h: &mut impl PrecompileHandle, proposal: UnboundedBytes) -> EvmResult<()> {
+        let (proposal, length_bound) = Self::decode_proposal(proposal)?;
+        let call = pallet_collective::Call::<Runtime, Instance>::execute {
+            proposal: Box::new(proposal),
+            length_bound,
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// Proposes the SCALE encoded `proposal`, which is executed right away for a `threshold`
+    /// below 2. Returns the index the proposal is voted on with otherwise.
+    #[precompile::public("propose(uint32,bytes)")]
+    fn propose(
+        h: &mut impl PrecompileHandle,
+        threshold: u32,
+        proposal: UnboundedBytes,
+    ) -> EvmResult<u32> {
+        let (proposal, length_bound) = Self::decode_proposal(proposal)?;
+        // Storage item: ProposalCount: u32
+        h.record_db_read::<Runtime>(u32::max_encoded_len())?;
+        let index = pallet_collective::ProposalCount::<Runtime, Instance>::get();
+
+        let call = pallet_collective::Call::<Runtime, Instance>::propose {
+            threshold,
+            proposal: Box::new(proposal),
+            length_bound,
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(index)
+    }
+
+    #[precompile::public("vote(bytes32,uint32,bool)")]
+    fn vote(
+        h: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+        index: u32,
+        approve: bool,
+    ) -> EvmResult<()> {
+        let call = pallet_collective::Call::<Runtime, Instance>::vote {
+            proposal: proposal_hash.into(),
+            index,
+            approve,
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// `ref_time` and `proof_size` bound the weight of the proposal and `length_bound` its
+    /// encoded length.
+    #[precompile::public("close(bytes32,uint32,uint64,uint64,uint32)")]
+    fn close(
+        h: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+        index: u32,
+        ref_time: u64,
+        proof_size: u64,
+        length_bound: u32,
+    ) -> EvmResult<()> {
+        let call = pallet_collective::Call::<Runtime, Instance>::close {
+            proposal_hash: proposal_hash.into(),
+            index,
+            proposal_weight_bound: Weight::from_parts(ref_time, proof_size),
+            length_bound,
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("members()")]
+    #[precompile::view]
+    fn members(h: &mut impl PrecompileHandle) -> EvmResult<Vec<Address>> {
+        // Storage item: Members: Vec<AccountId>
+        h.record_db_read::<Runtime>(Self::members_len())?;
+        Ok(pallet_collective::Members::<Runtime, Instance>::get()
+            .into_iter()
+            .map(|member| Address(member.into()))
+            .collect())
+    }
+
+    #[precompile::public("isMember(address)")]
+    #[precompile::view]
+    fn is_member(h: &mut impl PrecompileHandle, who: Address) -> EvmResult<bool> {
+        // Storage item: Members: Vec<AccountId>
+        h.record_db_read::<Runtime>(Self::members_len())?;
+        let who = Runtime::AddressMapping::into_account_id(who.0);
+        Ok(pallet_collective::Pallet::<Runtime, Instance>::is_member(&who))
+    }
+
+    /// Returns the prime member, or the zero address if there is none.
+    #[precompile::public("prime()")]
+    #[precompile::view]
+    fn prime(h: &mut impl PrecompileHandle) -> EvmResult<Address> {
+        // Storage item: Prime: AccountId
+        h.record_db_read::<Runtime>(Runtime::AccountId::max_encoded_len())?;
+        let prime = pallet_collective::Prime::<Runtime, Instance>::get()
+            .map(Into::into)
+            .unwrap_or_default();
+        Ok(Address(prime))
+    }
+
+    /// Returns the hashes of the open proposals.
+    #[precompile::public("proposals()")]
+    #[precompile::view]
+    fn proposals(h: &mut impl PrecompileHandle) -> EvmResult<Vec<H256>> {
+        // Storage item: Proposals: BoundedVec<Hash, MaxProposals>
+        h.record_db_read::<Runtime>(BoundedVec::<
+            Runtime::Hash,
+            <Runtime as pallet_collective::Config<Instance>>::MaxProposals,
+        >::max_encoded_len())?;
+        Ok(pallet_collective::Proposals::<Runtime, Instance>::get()
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Returns the SCALE encoded proposal with the hash `proposal_hash`. Reverts if it's
+    /// longer than `length_bound`, which the read is charged by, like in `close`.
+    #[precompile::public("proposalOf(bytes32,uint32)")]
+    #[precompile::view]
+    fn proposal_of(
+        h: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+        length_bound: u32,
+    ) -> EvmResult<UnboundedBytes> {
+        // Storage item: ProposalOf: Proposal, which is unbounded and charged by `length_bound`
+        h.record_db_read::<Runtime>(H256::max_encoded_len() + length_bound as usize)?;
+        let proposal = pallet_collective::ProposalOf::<Runtime, Instance>::get(
+            Runtime::Hash::from(proposal_hash),
+        )
+        .ok_or_else(|| Self::custom_err("Unable to get proposal"))?
+        .encode();
+        if proposal.len() > length_bound as usize {
+            return Err(RevertReason::custom("Proposal is longer than the length bound")
+                .in_field("lengthBound")
+                .into());
+        }
+        Ok(proposal.into())
+    }
+
+    /// Returns the hash a SCALE encoded proposal is voted on with.
+    #[precompile::public("proposalHash(bytes)")]
+    #[precompile::view]
+    fn proposal_hash(_: &mut impl PrecompileHandle, proposal: UnboundedBytes) -> EvmResult<H256> {
+        let (proposal, _) = Self::decode_proposal(proposal)?;
+        Ok(<Runtime as frame_system::Config>::Hashing::hash_of(&proposal).into())
+    }
+
+    fn decode_proposal(
+        proposal: UnboundedBytes,
+    ) -> EvmResult<(CollectiveProposal<Runtime, Instance>, u32)> {
+        let proposal: Vec<u8> = proposal.into();
+        let length_bound: u32 = proposal
+            .len()
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("uint32").in_field("proposal"))?;
+        let proposal = CollectiveProposal::<Runtime, Instance>::decode_with_depth_limit(
+            MAX_PROPOSAL_DEPTH,
+            &mut &*proposal,
+        )
+        .map_err(|_| RevertReason::custom("Unable to decode proposal").in_field("proposal"))?;
+        Ok((proposal, length_bound))
+    }
+
+    /// The length of the members, which are bounded by `MaxMembers`.
+    fn members_len() -> usize {
+        let max_members =
+            <Runtime as pallet_collective::Config<Instance>>::MaxMembers::get() as usize;
+        parity_scale_codec::Compact(max_members as u32).encoded_size()
+            + max_members * Runtime::AccountId::max_encoded_len()
+    }
+
+    fn custom_err(reason: &'static str) -> PrecompileFailure {
+        PrecompileFailure::Error { exit_status: evm::ExitError::Other(reason.into()) }
+    }
+}
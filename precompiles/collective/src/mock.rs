@@ -0,0 +1,42 @@
+use crate::CollectivePrecompile;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, ConstU64},
+    weights::Weight,
+};
+use frame_system::EnsureRoot;
+use sp_runtime::BuildStorage;
+
+precompile_test_utils::mock_runtime! {
+    precompile: CollectivePrecompile<Runtime, pallet_collective::Instance1>,
+    address: 2008,
+    pallets: {
+        Council: pallet_collective::<Instance1>,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000)],
+    genesis: |storage| {
+        pallet_collective::GenesisConfig::<Runtime, pallet_collective::Instance1> {
+            members: vec![alice(), bob()],
+            phantom: Default::default(),
+        }
+        .assimilate_storage(storage)
+        .unwrap();
+    },
+}
+
+parameter_types! {
+    pub MaxProposalWeight: Weight = Weight::from_parts(2_000_000_000_000, u64::MAX);
+}
+
+impl pallet_collective::Config<pallet_collective::Instance1> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = ConstU64<10>;
+    type MaxProposals = ConstU32<100>;
+    type MaxMembers = ConstU32<100>;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = ();
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type MaxProposalWeight = MaxProposalWeight;
+}
@@ -0,0 +1,184 @@
+use crate::mock::*;
+use frame_support::dispatch::GetDispatchInfo;
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256};
+use sp_runtime::traits::Hash;
+
+type PCall = crate::CollectivePrecompileCall<Runtime, pallet_collective::Instance1>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+fn proposal() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::set_storage { items: vec![] })
+}
+
+fn proposal_hash() -> H256 {
+    <Runtime as frame_system::Config>::Hashing::hash_of(&proposal())
+}
+
+#[test]
+fn member_views_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        pallet_collective::Prime::<Runtime, pallet_collective::Instance1>::put(bob());
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::members {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(vec![Address(alice().into()), Address(bob().into())]);
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::is_member { who: Address(bob().into()) },
+            )
+            .execute_returns(true);
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::is_member { who: Address(charlie().into()) },
+            )
+            .execute_returns(false);
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::prime {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(Address(bob().into()));
+    });
+}
+
+#[test]
+fn execute_works_for_members_only() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::execute { proposal: proposal().encode().into() },
+            )
+            .execute_returns(());
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::execute { proposal: proposal().encode().into() },
+            )
+            .execute_reverts(|output| String::from_utf8_lossy(output).contains("NotMember"));
+    });
+}
+
+#[test]
+fn execute_rejects_undecodable_proposal() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::execute { proposal: vec![0xff, 0xff].into() },
+            )
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("Unable to decode proposal")
+            });
+    });
+}
+
+#[test]
+fn propose_vote_and_close_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let length = proposal().encode().len() as u32;
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::propose { threshold: 2, proposal: proposal().encode().into() },
+            )
+            .execute_returns(0u32);
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::proposals {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(vec![proposal_hash()]);
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::proposal_of { proposal_hash: proposal_hash(), length_bound: length },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(UnboundedBytes::from(proposal().encode()));
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::proposal_of { proposal_hash: proposal_hash(), length_bound: length - 1 },
+            )
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("Proposal is longer than the length bound")
+            });
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::proposal_hash { proposal: proposal().encode().into() },
+            )
+            .execute_returns(proposal_hash());
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::vote { proposal_hash: proposal_hash(), index: 0, approve: true },
+            )
+            .execute_returns(());
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::vote { proposal_hash: proposal_hash(), index: 0, approve: true },
+            )
+            .execute_returns(());
+
+        let weight = proposal().get_dispatch_info().weight;
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::close {
+                    proposal_hash: proposal_hash(),
+                    index: 0,
+                    ref_time: weight.ref_time(),
+                    proof_size: weight.proof_size(),
+                    length_bound: length,
+                },
+            )
+            .execute_returns(());
+
+        assert!(
+            pallet_collective::Proposals::<Runtime, pallet_collective::Instance1>::get().is_empty()
+        );
+        assert_eq!(
+            pallet_collective::ProposalCount::<Runtime, pallet_collective::Instance1>::get(),
+            1
+        );
+    });
+}
+
+#[test]
+fn proposal_of_fails_for_unknown_proposal() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::proposal_of { proposal_hash: H256::repeat_byte(1), length_bound: 100 },
+            )
+            .execute_error(evm::ExitError::Other("Unable to get proposal".into()));
+    });
+}
@@ -0,0 +1,190 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(missing_docs)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::Currency,
+};
+use pallet_elections_phragmen::{Renouncing, SeatHolder, Voter};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{Compact, Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::{Get, H160, U256};
+use sp_runtime::traits::Dispatchable;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_elections_phragmen::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+pub struct ElectionsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> ElectionsPrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config + pallet_elections_phragmen::Config,
+    Runtime::AccountId: Into<H160>,
+    BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+    Runtime::RuntimeCall: From<pallet_elections_phragmen::Call<Runtime>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+{
+    /// Votes for `votes` with `value` locked, replacing any previous vote.
+    #[precompile::public("vote(address[],uint256)")]
+    fn vote(h: &mut impl PrecompileHandle, votes: Vec<Address>, value: U256) -> EvmResult<()> {
+        let votes = votes
+            .into_iter()
+            .map(|candidate| Runtime::AddressMapping::into_account_id(candidate.0))
+            .collect();
+        let value = Self::u256_to_amount(value)?;
+        let call = pallet_elections_phragmen::Call::<Runtime>::vote { votes, value };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("removeVoter()")]
+    fn remove_voter(h: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let call = pallet_elections_phragmen::Call::<Runtime>::remove_voter {};
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("submitCandidacy()")]
+    fn submit_candidacy(h: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let candidate_count = Self::candidates_of(h)?.len() as u32;
+        let call = pallet_elections_phragmen::Call::<Runtime>::submit_candidacy { candidate_count };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// Renounces the caller's seat as a member or runner-up, or its candidacy otherwise.
+    #[precompile::public("renounceCandidacy()")]
+    fn renounce_candidacy(h: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let who = Runtime::AddressMapping::into_account_id(h.context().caller);
+        let renouncing = if Self::members_of(h)?.iter().any(|member| member.who == who) {
+            Renouncing::Member
+        } else if Self::runners_up_of(h)?.iter().any(|runner_up| runner_up.who == who) {
+            Renouncing::RunnerUp
+        } else {
+            Renouncing::Candidate(Self::candidates_of(h)?.len() as u32)
+        };
+        let call = pallet_elections_phragmen::Call::<Runtime>::renounce_candidacy { renouncing };
+        RuntimeHelper::<Runtime>::try_dispatch(h, Some(who).into(), call)?;
+        Ok(())
+    }
+
+    /// Returns the candidates and their deposits.
+    #[precompile::public("candidates()")]
+    #[precompile::view]
+    fn candidates(h: &mut impl PrecompileHandle) -> EvmResult<Vec<(Address, U256)>> {
+        Ok(Self::candidates_of(h)?
+            .into_iter()
+            .map(|(candidate, deposit)| (Address(candidate.into()), deposit.into()))
+            .collect())
+    }
+
+    /// Returns the members with their stake and deposit.
+    #[precompile::public("members()")]
+    #[precompile::view]
+    fn members(h: &mut impl PrecompileHandle) -> EvmResult<Vec<(Address, U256, U256)>> {
+        Ok(Self::members_of(h)?.into_iter().map(Self::seat_holder).collect())
+    }
+
+    /// Returns the runners-up with their stake and deposit.
+    #[precompile::public("runnersUp()")]
+    #[precompile::view]
+    fn runners_up(h: &mut impl PrecompileHandle) -> EvmResult<Vec<(Address, U256, U256)>> {
+        Ok(Self::runners_up_of(h)?.into_iter().map(Self::seat_holder).collect())
+    }
+
+    /// Returns the candidates `who` votes for, the locked stake and the deposit of the vote.
+    #[precompile::public("votingOf(address)")]
+    #[precompile::view]
+    fn voting_of(
+        h: &mut impl PrecompileHandle,
+        who: Address,
+    ) -> EvmResult<(Vec<Address>, U256, U256)> {
+        // Storage item: Voting: Voter, with at most `MaxVotesPerVoter` votes
+        h.record_db_read::<Runtime>(
+            8 + Runtime::AccountId::max_encoded_len()
+                + Self::vec_len(
+                    Runtime::MaxVotesPerVoter::get(),
+                    Runtime::AccountId::max_encoded_len(),
+                )
+                + 2 * BalanceOf::<Runtime>::max_encoded_len(),
+        )?;
+        let who = Runtime::AddressMapping::into_account_id(who.0);
+        let Voter { votes, stake, deposit } =
+            pallet_elections_phragmen::Voting::<Runtime>::get(who);
+        Ok((
+            votes.into_iter().map(|candidate| Address(candidate.into())).collect(),
+            stake.into(),
+            deposit.into(),
+        ))
+    }
+
+    fn candidates_of(
+        h: &mut impl PrecompileHandle,
+    ) -> EvmResult<Vec<(Runtime::AccountId, BalanceOf<Runtime>)>> {
+        // Storage item: Candidates: Vec<(AccountId, Balance)>, with at most `MaxCandidates`
+        // candidates
+        h.record_db_read::<Runtime>(Self::vec_len(
+            Runtime::MaxCandidates::get(),
+            Runtime::AccountId::max_encoded_len() + BalanceOf::<Runtime>::max_encoded_len(),
+        ))?;
+        Ok(pallet_elections_phragmen::Candidates::<Runtime>::get())
+    }
+
+    fn members_of(
+        h: &mut impl PrecompileHandle,
+    ) -> EvmResult<Vec<SeatHolder<Runtime::AccountId, BalanceOf<Runtime>>>> {
+        // Storage item: Members: Vec<SeatHolder>, with at most `DesiredMembers` members
+        h.record_db_read::<Runtime>(Self::vec_len(
+            Runtime::DesiredMembers::get(),
+            Self::seat_holder_len(),
+        ))?;
+        Ok(pallet_elections_phragmen::Members::<Runtime>::get())
+    }
+
+    fn runners_up_of(
+        h: &mut impl PrecompileHandle,
+    ) -> EvmResult<Vec<SeatHolder<Runtime::AccountId, BalanceOf<Runtime>>>> {
+        // Storage item: RunnersUp: Vec<SeatHolder>, with at most `DesiredRunnersUp` runners-up
+        h.record_db_read::<Runtime>(Self::vec_len(
+            Runtime::DesiredRunnersUp::get(),
+            Self::seat_holder_len(),
+        ))?;
+        Ok(pallet_elections_phragmen::RunnersUp::<Runtime>::get())
+    }
+
+    fn seat_holder(
+        SeatHolder { who, stake, deposit }: SeatHolder<Runtime::AccountId, BalanceOf<Runtime>>,
+    ) -> (Address, U256, U256) {
+        (Address(who.into()), stake.into(), deposit.into())
+    }
+
+    fn seat_holder_len() -> usize {
+        Runtime::AccountId::max_encoded_len() + 2 * BalanceOf::<Runtime>::max_encoded_len()
+    }
+
+    /// The length of a vector of at most `count` items of `item_len` each.
+    fn vec_len(count: u32, item_len: usize) -> usize {
+        Compact(count).encoded_size() + count as usize * item_len
+    }
+
+    fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+        value
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("amount type").into())
+    }
+}
@@ -0,0 +1,40 @@
+use crate::ElectionsPrecompile;
+use frame_support::{
+    parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, LockIdentifier},
+};
+use sp_staking::currency_to_vote::SaturatingCurrencyToVote;
+
+precompile_test_utils::mock_runtime! {
+    precompile: ElectionsPrecompile<Runtime>,
+    address: 2010,
+    pallets: {
+        Elections: pallet_elections_phragmen,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000), (charlie(), 1_000_000)],
+}
+
+parameter_types! {
+    pub const ElectionsPalletId: LockIdentifier = *b"phrelect";
+}
+
+impl pallet_elections_phragmen::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type PalletId = ElectionsPalletId;
+    type Currency = Balances;
+    type ChangeMembers = ();
+    type InitializeMembers = ();
+    type CurrencyToVote = SaturatingCurrencyToVote;
+    type CandidacyBond = ConstU128<100>;
+    type VotingBondBase = ConstU128<10>;
+    type VotingBondFactor = ConstU128<2>;
+    type LoserCandidate = ();
+    type KickedMember = ();
+    type DesiredMembers = ConstU32<2>;
+    type DesiredRunnersUp = ConstU32<2>;
+    type TermDuration = ConstU64<10>;
+    type MaxVoters = ConstU32<100>;
+    type MaxVotesPerVoter = ConstU32<16>;
+    type MaxCandidates = ConstU32<100>;
+    type WeightInfo = ();
+}
@@ -0,0 +1,130 @@
+use crate::mock::*;
+use frame_support::{assert_ok, traits::ReservableCurrency};
+use pallet_elections_phragmen::SeatHolder;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+
+type PCall = crate::ElectionsPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+#[test]
+fn submit_and_renounce_candidacy_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::submit_candidacy {})
+            .execute_returns(());
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::submit_candidacy {})
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::candidates {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(vec![
+                (Address(alice().into()), U256::from(100)),
+                (Address(bob().into()), U256::from(100)),
+            ]);
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::renounce_candidacy {})
+            .execute_returns(());
+
+        assert_eq!(pallet_elections_phragmen::Candidates::<Runtime>::get(), vec![(alice(), 100)]);
+        assert_eq!(Balances::reserved_balance(bob()), 0);
+    });
+}
+
+#[test]
+fn renounce_candidacy_renounces_seats() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Balances::reserve(&alice(), 100));
+        assert_ok!(Balances::reserve(&bob(), 100));
+        pallet_elections_phragmen::Members::<Runtime>::put(vec![SeatHolder {
+            who: alice(),
+            stake: 500,
+            deposit: 100,
+        }]);
+        pallet_elections_phragmen::RunnersUp::<Runtime>::put(vec![SeatHolder {
+            who: bob(),
+            stake: 300,
+            deposit: 100,
+        }]);
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::members {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(vec![(Address(alice().into()), U256::from(500), U256::from(100))]);
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::runners_up {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(vec![(Address(bob().into()), U256::from(300), U256::from(100))]);
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::renounce_candidacy {})
+            .execute_returns(());
+        assert!(pallet_elections_phragmen::RunnersUp::<Runtime>::get().is_empty());
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::renounce_candidacy {})
+            .execute_returns(());
+        assert!(pallet_elections_phragmen::Members::<Runtime>::get().is_empty());
+    });
+}
+
+#[test]
+fn vote_and_remove_voter_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Elections::submit_candidacy(RuntimeOrigin::signed(alice()), 0));
+
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::vote { votes: vec![Address(alice().into())], value: U256::from(1000) },
+            )
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::voting_of { who: Address(charlie().into()) },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((vec![Address(alice().into())], U256::from(1000), U256::from(12)));
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::remove_voter {})
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::voting_of { who: Address(charlie().into()) },
+            )
+            .execute_returns((Vec::<Address>::new(), U256::zero(), U256::zero()));
+    });
+}
+
+#[test]
+fn vote_rejects_amount_overflow() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                charlie(),
+                precompile_address(),
+                PCall::vote { votes: vec![Address(alice().into())], value: U256::MAX },
+            )
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("Value is too large for amount type")
+            });
+    });
+}
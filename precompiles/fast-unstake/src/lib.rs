@@ -0,0 +1,99 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(missing_docs)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::Currency,
+};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::{H160, U256};
+use sp_runtime::traits::Dispatchable;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_fast_unstake::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+pub struct FastUnstakePrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> FastUnstakePrecompile<Runtime>
+where
+    Runtime: pallet_evm::Config + pallet_fast_unstake::Config,
+    Runtime::AccountId: Into<H160>,
+    BalanceOf<Runtime>: Into<U256>,
+    Runtime::RuntimeCall: From<pallet_fast_unstake::Call<Runtime>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+{
+    /// Queues the stash controlled by the caller to be unbonded without waiting for the bonding
+    /// duration, which happens if it wasn't exposed in any of the recent eras. Reserves the
+    /// fast-unstake deposit, which is slashed otherwise.
+    #[precompile::public("registerFastUnstake()")]
+    fn register_fast_unstake(h: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let call = pallet_fast_unstake::Call::<Runtime>::register_fast_unstake {};
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// Removes the stash controlled by the caller from the queue and releases its deposit. The
+    /// stash stays unbonding.
+    #[precompile::public("deregister()")]
+    fn deregister(h: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let call = pallet_fast_unstake::Call::<Runtime>::deregister {};
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// Returns whether `stash` waits in the queue. Stashes being checked are listed by `head`
+    /// instead.
+    #[precompile::public("isQueued(address)")]
+    #[precompile::view]
+    fn is_queued(h: &mut impl PrecompileHandle, stash: Address) -> EvmResult<bool> {
+        // Storage item: Queue: BalanceOf
+        h.record_db_read::<Runtime>(
+            8 + Runtime::AccountId::max_encoded_len() + BalanceOf::<Runtime>::max_encoded_len(),
+        )?;
+        let stash = Runtime::AddressMapping::into_account_id(stash.0);
+        Ok(pallet_fast_unstake::Queue::<Runtime>::contains_key(stash))
+    }
+
+    /// Returns the stashes being checked with their deposits, and the eras checked so far. Both
+    /// lists are empty if no stash is being checked.
+    #[precompile::public("head()")]
+    #[precompile::view]
+    fn head(h: &mut impl PrecompileHandle) -> EvmResult<(Vec<(Address, U256)>, Vec<u32>)> {
+        // Storage item: Head: UnstakeRequest
+        h.record_db_read::<Runtime>(
+            pallet_fast_unstake::types::UnstakeRequest::<Runtime>::max_encoded_len(),
+        )?;
+        let Some(head) = pallet_fast_unstake::Head::<Runtime>::get() else {
+            return Ok((Vec::new(), Vec::new()));
+        };
+        let stashes = head
+            .stashes
+            .into_iter()
+            .map(|(stash, deposit)| (Address(stash.into()), deposit.into()))
+            .collect();
+        Ok((stashes, head.checked.into_inner()))
+    }
+
+    #[precompile::public("counterForQueue()")]
+    #[precompile::view]
+    fn counter_for_queue(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: CounterForQueue: u32
+        h.record_db_read::<Runtime>(u32::max_encoded_len())?;
+        Ok(pallet_fast_unstake::Queue::<Runtime>::count())
+    }
+}
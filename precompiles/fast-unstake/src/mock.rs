@@ -0,0 +1,76 @@
+use crate::FastUnstakePrecompile;
+use frame_election_provider_support::{onchain, SequentialPhragmen};
+use frame_support::{
+    parameter_types,
+    traits::{ConstU128, ConstU32},
+};
+use sp_runtime::Perbill;
+use sp_staking::currency_to_vote::SaturatingCurrencyToVote;
+
+precompile_test_utils::mock_runtime! {
+    precompile: FastUnstakePrecompile<Runtime>,
+    address: 2012,
+    pallets: {
+        Staking: pallet_staking,
+        FastUnstake: pallet_fast_unstake,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000)],
+}
+
+parameter_types! {
+    pub const SessionsPerEra: sp_staking::SessionIndex = 6;
+    pub const BondingDuration: sp_staking::EraIndex = 3;
+    pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
+}
+
+pub struct OnChainSeqPhragmen;
+impl onchain::Config for OnChainSeqPhragmen {
+    type System = Runtime;
+    type Solver = SequentialPhragmen<AccountId, Perbill>;
+    type DataProvider = Staking;
+    type WeightInfo = ();
+    type MaxWinners = ConstU32<100>;
+    type Bounds = ();
+}
+
+impl pallet_staking::Config for Runtime {
+    type NominationsQuota = pallet_staking::FixedNominationsQuota<16>;
+    type Currency = Balances;
+    type CurrencyBalance = Balance;
+    type UnixTime = Timestamp;
+    type CurrencyToVote = SaturatingCurrencyToVote;
+    type RewardRemainder = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Slash = ();
+    type Reward = ();
+    type SessionsPerEra = SessionsPerEra;
+    type BondingDuration = BondingDuration;
+    type SlashDeferDuration = ();
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type SessionInterface = ();
+    type EraPayout = ();
+    type NextNewSession = ();
+    type MaxExposurePageSize = ConstU32<64>;
+    type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
+    type ElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
+    type GenesisElectionProvider = Self::ElectionProvider;
+    type VoterList = pallet_staking::UseNominatorsAndValidatorsMap<Self>;
+    type TargetList = pallet_staking::UseValidatorsMap<Self>;
+    type MaxUnlockingChunks = ConstU32<32>;
+    type HistoryDepth = ConstU32<84>;
+    type EventListeners = ();
+    type MaxControllersInDeprecationBatch = ConstU32<100>;
+    type WeightInfo = ();
+    type BenchmarkingConfig = pallet_staking::TestBenchmarkingConfig;
+}
+
+impl pallet_fast_unstake::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type Deposit = ConstU128<100>;
+    type ControlOrigin = frame_system::EnsureRoot<AccountId>;
+    type BatchSize = ConstU32<4>;
+    type MaxErasToCheckPerBlock = ConstU32<1>;
+    type WeightInfo = ();
+    type Staking = Staking;
+}
@@ -0,0 +1,87 @@
+use crate::mock::*;
+use frame_support::assert_ok;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+
+type PCall = crate::FastUnstakePrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+fn bond_and_enable_fast_unstake() {
+    assert_ok!(Staking::bond(
+        RuntimeOrigin::signed(alice()),
+        1_000,
+        pallet_staking::RewardDestination::Staked
+    ));
+    assert_ok!(FastUnstake::control(RuntimeOrigin::root(), 1));
+}
+
+#[test]
+fn register_and_deregister_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        bond_and_enable_fast_unstake();
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::register_fast_unstake {})
+            .execute_returns(());
+        assert_eq!(Balances::reserved_balance(alice()), 100);
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::is_queued { stash: Address(alice().into()) },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(true);
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::counter_for_queue {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(1u32);
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::deregister {})
+            .execute_returns(());
+        assert_eq!(Balances::reserved_balance(alice()), 0);
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::is_queued { stash: Address(alice().into()) },
+            )
+            .execute_returns(false);
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::counter_for_queue {})
+            .execute_returns(0u32);
+    });
+}
+
+#[test]
+fn register_requires_a_bonded_controller() {
+    ExtBuilder::default().build().execute_with(|| {
+        bond_and_enable_fast_unstake();
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::register_fast_unstake {})
+            .execute_reverts(|output| String::from_utf8_lossy(output).contains("NotController"));
+    });
+}
+
+#[test]
+fn head_is_empty_when_nothing_is_checked() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::head {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((Vec::<(Address, U256)>::new(), Vec::<u32>::new()));
+    });
+}
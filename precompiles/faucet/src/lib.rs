@@ -12,6 +12,12 @@ use sp_core::{H160, U256};
 use sp_runtime::traits::{Dispatchable, StaticLookup};
 use sp_std::marker::PhantomData;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 pub struct FaucetPrecompile<Runtime>(PhantomData<Runtime>);
 
 type BalanceOf<Runtime> = <<Runtime as pallet_faucet::Config>::Currency as Currency<
@@ -31,17 +37,35 @@ where
     <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 {
-    #[precompile::public("requestFunds(address,uint256)")]
-    fn request_funds(h: &mut impl PrecompileHandle, who: Address, amount: U256) -> EvmResult<()> {
-        let who = Runtime::AddressMapping::into_account_id(who.0);
+    /// Requests `amount` for the caller.
+    #[precompile::public("requestFunds(uint256)")]
+    fn request_funds(h: &mut impl PrecompileHandle, amount: U256) -> EvmResult<()> {
         let amount = Self::u256_to_amount(amount)?;
 
-        let call = pallet_faucet::Call::<Runtime>::request_funds { who, amount };
+        let call = pallet_faucet::Call::<Runtime>::request_funds_signed { amount };
         let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
         RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
         Ok(())
     }
 
+    /// Requests `amount` for `who`, which must be the caller.
+    ///
+    /// Kept for callers of the former selector that let anyone request funds for any address.
+    #[precompile::public("requestFunds(address,uint256)")]
+    fn request_funds_for(
+        h: &mut impl PrecompileHandle,
+        who: Address,
+        amount: U256,
+    ) -> EvmResult<()> {
+        if who.0 != h.context().caller {
+            return Err(RevertReason::custom("Funds can only be requested for the caller")
+                .in_field("who")
+                .into());
+        }
+
+        Self::request_funds(h, amount)
+    }
+
     fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
         value
             .try_into()
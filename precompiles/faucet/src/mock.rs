@@ -0,0 +1,36 @@
+use crate::FaucetPrecompile;
+use fp_account::{EthereumSignature, EthereumSigner};
+use frame_support::{parameter_types, PalletId};
+
+precompile_test_utils::mock_runtime! {
+    precompile: FaucetPrecompile<Runtime>,
+    address: 2005,
+    pallets: {
+        Faucet: pallet_faucet,
+    },
+    balances: vec![(Faucet::account_id(), 1_000_000), (alice(), 1_000)],
+}
+
+parameter_types! {
+    pub const AccumulationPeriod: u64 = 100;
+    pub const FaucetAmount: Balance = 1000;
+    pub const MaxEmissionPerPeriod: Balance = 5000;
+    pub const DefaultPowDifficulty: u32 = 8;
+    pub const PowValidity: u64 = 5;
+    pub const FaucetPalletId: PalletId = PalletId(*b"pa/facet");
+}
+
+impl pallet_faucet::Config for Runtime {
+    type AccumulationPeriod = AccumulationPeriod;
+    type FaucetAmount = FaucetAmount;
+    type MaxEmissionPerPeriod = MaxEmissionPerPeriod;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type DefaultPowDifficulty = DefaultPowDifficulty;
+    type PowValidity = PowValidity;
+    type AttestationSignature = EthereumSignature;
+    type AttestationSigner = EthereumSigner;
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type PalletId = FaucetPalletId;
+    type WeightInfo = ();
+}
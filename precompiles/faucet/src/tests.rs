@@ -0,0 +1,88 @@
+use crate::mock::*;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+
+type PCall = crate::FaucetPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+#[test]
+fn request_funds_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::request_funds { amount: 500.into() })
+            .execute_returns(());
+
+        assert_eq!(Balances::free_balance(bob()), 500);
+        assert_eq!(Faucet::requests(bob()).0, 500);
+    });
+}
+
+#[test]
+fn request_funds_cannot_pay_another_address() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::request_funds_for { who: Address(bob().into()), amount: 500.into() },
+            )
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output)
+                    .contains("Funds can only be requested for the caller")
+            });
+        assert_eq!(Balances::free_balance(bob()), 0);
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::request_funds_for { who: Address(alice().into()), amount: 500.into() },
+            )
+            .execute_returns(());
+        assert_eq!(Balances::free_balance(bob()), 0);
+        assert_eq!(Faucet::requests(alice()).0, 500);
+        assert_eq!(Faucet::requests(bob()).0, 0);
+    });
+}
+
+#[test]
+fn request_funds_rejects_amount_above_limit() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::request_funds { amount: 1001.into() })
+            .execute_reverts(|output| String::from_utf8_lossy(output).contains("AmountTooHigh"));
+    });
+}
+
+#[test]
+fn request_funds_shares_limit_with_previous_requests() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::request_funds { amount: 800.into() })
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::request_funds { amount: 300.into() })
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("RequestLimitExceeded")
+            });
+    });
+}
+
+#[test]
+fn request_funds_rejects_amount_overflow() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::request_funds { amount: U256::MAX })
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("Value is too large for amount type")
+            });
+    });
+}
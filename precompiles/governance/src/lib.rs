@@ -4,14 +4,25 @@
 use fp_evm::PrecompileHandle;
 use frame_support::{
     dispatch::{GetDispatchInfo, PostDispatchInfo},
-    traits::{Bounded, BoundedInline, Currency, IsType},
+    traits::{Bounded, BoundedInline, Currency, IsType, QueryPreimage},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_evm::{AddressMapping, PrecompileFailure};
+use parity_scale_codec::MaxEncodedLen;
 use precompile_utils::prelude::*;
 use sp_core::{H160, H256, U256};
-use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_runtime::{
+    traits::{Dispatchable, StaticLookup},
+    BoundedVec, SaturatedConversion,
+};
 use sp_std::{marker::PhantomData, vec::Vec};
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 type BalanceOf<Runtime> = <<Runtime as pallet_democracy::Config>::Currency as Currency<
     <Runtime as frame_system::Config>::AccountId,
 >>::Balance;
@@ -21,7 +32,7 @@ pub struct GovernancePrecompile<Runtime>(PhantomData<Runtime>);
 #[precompile_utils::precompile]
 impl<Runtime> GovernancePrecompile<Runtime>
 where
-    Runtime: pallet_evm::Config + pallet_democracy::Config,
+    Runtime: pallet_evm::Config + pallet_democracy::Config + pallet_preimage::Config,
     Runtime::AccountId: Into<H160>,
     Runtime::Hash: IsType<H256>,
     BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
@@ -30,28 +41,45 @@ where
     <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 {
-    #[precompile::public("propose(uint8[],uint256)")]
+    #[precompile::public("propose(bytes,uint256)")]
     fn propose_inline(
         h: &mut impl PrecompileHandle,
-        bounded_call: Vec<u8>,
+        bounded_call: UnboundedBytes,
         value: U256,
     ) -> EvmResult<()> {
-        let bounded_call = BoundedInline::try_from(bounded_call)
+        let bounded_call = BoundedInline::try_from(Vec::from(bounded_call))
             .map_err(|_| Self::custom_err("Unable to parse bounded call"))?;
         let value = Self::u256_to_amount(value)?;
         Self::_propose(h, Bounded::Inline(bounded_call), value)
     }
 
+    /// Proposes the call noted in `pallet_preimage` under `proposal_hash`.
     #[precompile::public("propose(bytes32,uint256)")]
     fn propose_lookup(
         h: &mut impl PrecompileHandle,
         proposal_hash: H256,
         value: U256,
     ) -> EvmResult<()> {
-        let hash = proposal_hash;
-        let len = hash.0.len() as u32;
+        // Storage items: RequestStatusFor and the legacy StatusFor
+        h.record_db_read::<Runtime>(Self::preimage_status_len())?;
+        h.record_db_read::<Runtime>(Self::preimage_status_len())?;
+        let len = <pallet_preimage::Pallet<Runtime> as QueryPreimage>::len(&proposal_hash.into())
+            .ok_or_else(|| Self::custom_err("Unable to get preimage length"))?;
         let value = Self::u256_to_amount(value)?;
-        Self::_propose(h, Bounded::Lookup { hash: hash.into(), len }, value)
+        Self::_propose(h, Bounded::Lookup { hash: proposal_hash.into(), len }, value)
+    }
+
+    /// Proposes the call with the encoded length `len` under `proposal_hash`, which doesn't have
+    /// to be noted yet.
+    #[precompile::public("propose(bytes32,uint32,uint256)")]
+    fn propose_lookup_with_len(
+        h: &mut impl PrecompileHandle,
+        proposal_hash: H256,
+        len: u32,
+        value: U256,
+    ) -> EvmResult<()> {
+        let value = Self::u256_to_amount(value)?;
+        Self::_propose(h, Bounded::Lookup { hash: proposal_hash.into(), len }, value)
     }
 
     fn _propose(
@@ -113,6 +141,270 @@ where
         Ok(())
     }
 
+    #[precompile::public("second(uint32)")]
+    fn second(h: &mut impl PrecompileHandle, proposal: u32) -> EvmResult<()> {
+        let call = pallet_democracy::Call::<Runtime>::second { proposal };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("delegate(address,uint8,uint256)")]
+    fn delegate(
+        h: &mut impl PrecompileHandle,
+        to: Address,
+        conviction: u8,
+        balance: U256,
+    ) -> EvmResult<()> {
+        let to = Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(to.0));
+        let conviction = pallet_democracy::Conviction::try_from(conviction)
+            .map_err(|_| Self::custom_err("Unable to parse conviction"))?;
+        let balance = Self::u256_to_amount(balance)?;
+        let call = pallet_democracy::Call::<Runtime>::delegate { to, conviction, balance };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("undelegate()")]
+    fn undelegate(h: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let call = pallet_democracy::Call::<Runtime>::undelegate {};
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("unlock(address)")]
+    fn unlock(h: &mut impl PrecompileHandle, target: Address) -> EvmResult<()> {
+        let target = Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(target.0));
+        let call = pallet_democracy::Call::<Runtime>::unlock { target };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("removeOtherVote(address,uint32)")]
+    fn remove_other_vote(
+        h: &mut impl PrecompileHandle,
+        target: Address,
+        index: u32,
+    ) -> EvmResult<()> {
+        let target = Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(target.0));
+        let call = pallet_democracy::Call::<Runtime>::remove_other_vote { target, index };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("publicPropCount()")]
+    #[precompile::view]
+    fn public_prop_count(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: PublicPropCount: PropIndex
+        h.record_db_read::<Runtime>(pallet_democracy::PropIndex::max_encoded_len())?;
+        Ok(pallet_democracy::PublicPropCount::<Runtime>::get())
+    }
+
+    /// Returns the index, the proposal hash and the proposer of every public proposal.
+    #[precompile::public("publicProps()")]
+    #[precompile::view]
+    fn public_props(h: &mut impl PrecompileHandle) -> EvmResult<Vec<(u32, H256, Address)>> {
+        // Storage item: PublicProps: BoundedVec<(PropIndex, BoundedCallOf, AccountId), MaxProposals>
+        h.record_db_read::<Runtime>(BoundedVec::<
+            (
+                pallet_democracy::PropIndex,
+                pallet_democracy::BoundedCallOf<Runtime>,
+                Runtime::AccountId,
+            ),
+            Runtime::MaxProposals,
+        >::max_encoded_len())?;
+        Ok(pallet_democracy::PublicProps::<Runtime>::get()
+            .into_iter()
+            .map(|(index, proposal, proposer)| {
+                (index, proposal.hash().into(), Address(proposer.into()))
+            })
+            .collect())
+    }
+
+    /// Returns the accounts that seconded the proposal, starting with the proposer, and the
+    /// deposit of each of them.
+    #[precompile::public("depositOf(uint32)")]
+    #[precompile::view]
+    fn deposit_of(
+        h: &mut impl PrecompileHandle,
+        prop_index: u32,
+    ) -> EvmResult<(Vec<Address>, U256)> {
+        // Storage item: DepositOf: (BoundedVec<AccountId, MaxDeposits>, Balance)
+        h.record_db_read::<Runtime>(
+            Self::index_key_len()
+                + <(BoundedVec<Runtime::AccountId, Runtime::MaxDeposits>, BalanceOf<Runtime>)>::max_encoded_len(),
+        )?;
+        let (depositors, deposit) = pallet_democracy::DepositOf::<Runtime>::get(prop_index)
+            .ok_or_else(|| Self::custom_err("Unable to get deposit"))?;
+        Ok((
+            depositors.into_iter().map(|depositor| Address(depositor.into())).collect(),
+            deposit.into(),
+        ))
+    }
+
+    #[precompile::public("referendumCount()")]
+    #[precompile::view]
+    fn referendum_count(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: ReferendumCount: ReferendumIndex
+        h.record_db_read::<Runtime>(pallet_democracy::ReferendumIndex::max_encoded_len())?;
+        Ok(pallet_democracy::ReferendumCount::<Runtime>::get())
+    }
+
+    #[precompile::public("lowestUnbaked()")]
+    #[precompile::view]
+    fn lowest_unbaked(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: LowestUnbaked: ReferendumIndex
+        h.record_db_read::<Runtime>(pallet_democracy::ReferendumIndex::max_encoded_len())?;
+        Ok(pallet_democracy::LowestUnbaked::<Runtime>::get())
+    }
+
+    /// Returns the status, the `(ayes, nays, turnout)` tally, the end block, the threshold and the
+    /// proposal hash of the referendum.
+    ///
+    /// The status is 0 for an ongoing referendum, 1 for an approved and 2 for a rejected one. The
+    /// threshold is 0 for `SuperMajorityApprove`, 1 for `SuperMajorityAgainst` and 2 for
+    /// `SimpleMajority`. A finished referendum only has its status and end block set.
+    #[precompile::public("referendumInfo(uint32)")]
+    #[precompile::view]
+    #[allow(clippy::type_complexity)]
+    fn referendum_info(
+        h: &mut impl PrecompileHandle,
+        ref_index: u32,
+    ) -> EvmResult<(u8, (U256, U256, U256), u32, u8, H256)> {
+        // Storage item: ReferendumInfoOf: ReferendumInfo
+        h.record_db_read::<Runtime>(
+            Self::index_key_len()
+                + pallet_democracy::ReferendumInfo::<
+                    BlockNumberFor<Runtime>,
+                    pallet_democracy::BoundedCallOf<Runtime>,
+                    BalanceOf<Runtime>,
+                >::max_encoded_len(),
+        )?;
+        let info = pallet_democracy::ReferendumInfoOf::<Runtime>::get(ref_index)
+            .ok_or_else(|| Self::custom_err("Unable to get referendum info"))?;
+        let info = match info {
+            pallet_democracy::ReferendumInfo::Ongoing(status) => {
+                let pallet_democracy::Tally { ayes, nays, turnout } = status.tally;
+                let threshold = match status.threshold {
+                    pallet_democracy::VoteThreshold::SuperMajorityApprove => 0,
+                    pallet_democracy::VoteThreshold::SuperMajorityAgainst => 1,
+                    pallet_democracy::VoteThreshold::SimpleMajority => 2,
+                };
+                (
+                    0,
+                    (ayes.into(), nays.into(), turnout.into()),
+                    status.end.saturated_into(),
+                    threshold,
+                    status.proposal.hash().into(),
+                )
+            },
+            pallet_democracy::ReferendumInfo::Finished { approved, end } => (
+                if approved { 1 } else { 2 },
+                Default::default(),
+                end.saturated_into(),
+                0,
+                H256::zero(),
+            ),
+        };
+        Ok(info)
+    }
+
+    /// Returns whether the account delegates its votes, its direct votes, the delegation target,
+    /// conviction and balance, the `(votes, capital)` delegated to the account and its locked
+    /// balance.
+    ///
+    /// Each direct vote is `(refIndex, isSplit, aye, conviction, ayeBalance, nayBalance)`. A
+    /// standard vote has its balance on the side it votes for. Unused fields are zero.
+    #[precompile::public("votingOf(address)")]
+    #[precompile::view]
+    #[allow(clippy::type_complexity)]
+    fn voting_of(
+        h: &mut impl PrecompileHandle,
+        who: Address,
+    ) -> EvmResult<(
+        bool,
+        Vec<(u32, bool, bool, u8, U256, U256)>,
+        Address,
+        u8,
+        U256,
+        (U256, U256),
+        U256,
+    )> {
+        // Storage item: VotingOf: Voting
+        h.record_db_read::<Runtime>(
+            8 + Runtime::AccountId::max_encoded_len()
+                + pallet_democracy::Voting::<
+                    BalanceOf<Runtime>,
+                    Runtime::AccountId,
+                    BlockNumberFor<Runtime>,
+                    Runtime::MaxVotes,
+                >::max_encoded_len(),
+        )?;
+        let who = Runtime::AddressMapping::into_account_id(who.0);
+        let voting = pallet_democracy::VotingOf::<Runtime>::get(who);
+        let locked = voting.locked_balance().into();
+        let voting_of = match voting {
+            pallet_democracy::Voting::Direct { votes, delegations, .. } => (
+                false,
+                votes
+                    .into_iter()
+                    .map(|(ref_index, vote)| match vote {
+                        pallet_democracy::AccountVote::Standard { vote, balance } => {
+                            let (aye, nay) = if vote.aye {
+                                (balance.into(), U256::zero())
+                            } else {
+                                (U256::zero(), balance.into())
+                            };
+                            (ref_index, false, vote.aye, vote.conviction.into(), aye, nay)
+                        },
+                        pallet_democracy::AccountVote::Split { aye, nay } => {
+                            (ref_index, true, false, 0, aye.into(), nay.into())
+                        },
+                    })
+                    .collect(),
+                Address(H160::zero()),
+                0,
+                U256::zero(),
+                (delegations.votes.into(), delegations.capital.into()),
+                locked,
+            ),
+            pallet_democracy::Voting::Delegating {
+                balance,
+                target,
+                conviction,
+                delegations,
+                ..
+            } => (
+                true,
+                Vec::new(),
+                Address(target.into()),
+                conviction.into(),
+                balance.into(),
+                (delegations.votes.into(), delegations.capital.into()),
+                locked,
+            ),
+        };
+        Ok(voting_of)
+    }
+
+    /// The length of a storage map key hashed with `Twox64Concat` over a proposal or referendum
+    /// index.
+    fn index_key_len() -> usize {
+        8 + u32::max_encoded_len()
+    }
+
+    /// The length of a preimage status entry, bounding both `RequestStatusFor` and the legacy
+    /// `StatusFor`, whose deposit is a balance.
+    fn preimage_status_len() -> usize {
+        H256::max_encoded_len()
+            + pallet_preimage::RequestStatus::<Runtime::AccountId, Runtime::Consideration>::max_encoded_len()
+            + BalanceOf::<Runtime>::max_encoded_len()
+    }
+
     fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
         value
             .try_into()
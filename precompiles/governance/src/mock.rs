@@ -0,0 +1,75 @@
+use crate::GovernancePrecompile;
+use frame_support::{
+    parameter_types,
+    traits::{ConstBool, ConstU128, ConstU32, ConstU64, EqualPrivilegeOnly},
+    weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+
+precompile_test_utils::mock_runtime! {
+    precompile: GovernancePrecompile<Runtime>,
+    address: 2001,
+    pallets: {
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler,
+        Democracy: pallet_democracy,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000)],
+}
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Weight::from_parts(2_000_000_000_000, u64::MAX);
+}
+
+impl pallet_preimage::Config for Runtime {
+    type WeightInfo = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type Consideration = ();
+}
+
+impl pallet_scheduler::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = EnsureRoot<AccountId>;
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type WeightInfo = ();
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type Preimages = Preimage;
+}
+
+impl pallet_democracy::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type EnactmentPeriod = ConstU64<2>;
+    type LaunchPeriod = ConstU64<10>;
+    type VotingPeriod = ConstU64<10>;
+    type VoteLockingPeriod = ConstU64<3>;
+    type MinimumDeposit = ConstU128<10>;
+    type ExternalOrigin = EnsureRoot<AccountId>;
+    type ExternalMajorityOrigin = EnsureRoot<AccountId>;
+    type ExternalDefaultOrigin = EnsureRoot<AccountId>;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type FastTrackOrigin = EnsureRoot<AccountId>;
+    type InstantOrigin = EnsureRoot<AccountId>;
+    type InstantAllowed = ConstBool<true>;
+    type FastTrackVotingPeriod = ConstU64<2>;
+    type CancellationOrigin = EnsureRoot<AccountId>;
+    type CancelProposalOrigin = EnsureRoot<AccountId>;
+    type BlacklistOrigin = EnsureRoot<AccountId>;
+    type VetoOrigin = EnsureSigned<AccountId>;
+    type CooloffPeriod = ConstU64<2>;
+    type Slash = ();
+    type Scheduler = Scheduler;
+    type PalletsOrigin = OriginCaller;
+    type MaxVotes = ConstU32<100>;
+    type WeightInfo = ();
+    type MaxProposals = ConstU32<100>;
+    type Preimages = Preimage;
+    type MaxDeposits = ConstU32<100>;
+    type MaxBlacklisted = ConstU32<100>;
+}
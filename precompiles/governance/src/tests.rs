@@ -0,0 +1,340 @@
+use crate::mock::*;
+use frame_support::{
+    assert_ok,
+    traits::{Bounded, StorePreimage},
+};
+use pallet_democracy::{BoundedCallOf, Conviction, ReferendumInfo, Vote, VoteThreshold, Voting};
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::Hash;
+
+type PCall = crate::GovernancePrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+fn proposal() -> BoundedCallOf<Runtime> {
+    let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![1, 2, 3] });
+    Preimage::bound(call).unwrap()
+}
+
+#[test]
+fn proposal_views_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Democracy::propose(RuntimeOrigin::signed(alice()), proposal(), 100));
+        assert_ok!(Democracy::second(RuntimeOrigin::signed(bob()), 0));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::public_prop_count {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(1u32);
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::public_props {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(vec![(0u32, proposal().hash(), Address(alice().into()))]);
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::deposit_of { prop_index: 0 })
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((
+                vec![Address(alice().into()), Address(bob().into())],
+                U256::from(100),
+            ));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::deposit_of { prop_index: 1 })
+            .execute_error(evm::ExitError::Other("Unable to get deposit".into()));
+    });
+}
+
+#[test]
+fn referendum_views_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let index = Democracy::internal_start_referendum(
+            proposal(),
+            VoteThreshold::SuperMajorityApprove,
+            0,
+        );
+        assert_ok!(Democracy::vote(
+            RuntimeOrigin::signed(alice()),
+            index,
+            pallet_democracy::AccountVote::Standard {
+                vote: Vote { aye: true, conviction: Conviction::Locked1x },
+                balance: 100,
+            }
+        ));
+        pallet_democracy::ReferendumInfoOf::<Runtime>::insert(
+            1,
+            ReferendumInfo::Finished { approved: false, end: 5 },
+        );
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::referendum_count {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(1u32);
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::lowest_unbaked {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(0u32);
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::referendum_info { ref_index: 0 })
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((
+                0u8,
+                (U256::from(100), U256::zero(), U256::from(100)),
+                11u32,
+                0u8,
+                proposal().hash(),
+            ));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::referendum_info { ref_index: 1 })
+            .execute_returns((
+                2u8,
+                (U256::zero(), U256::zero(), U256::zero()),
+                5u32,
+                0u8,
+                H256::zero(),
+            ));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::referendum_info { ref_index: 2 })
+            .execute_error(evm::ExitError::Other("Unable to get referendum info".into()));
+    });
+}
+
+#[test]
+fn voting_of_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let index =
+            Democracy::internal_start_referendum(proposal(), VoteThreshold::SimpleMajority, 0);
+        assert_ok!(Democracy::vote(
+            RuntimeOrigin::signed(alice()),
+            index,
+            pallet_democracy::AccountVote::Split { aye: 30, nay: 20 }
+        ));
+        assert_ok!(Democracy::delegate(
+            RuntimeOrigin::signed(bob()),
+            alice(),
+            Conviction::Locked2x,
+            50
+        ));
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::voting_of { who: Address(alice().into()) },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((
+                false,
+                vec![(0u32, true, false, 0u8, U256::from(30), U256::from(20))],
+                Address(H160::zero()),
+                0u8,
+                U256::zero(),
+                (U256::from(100), U256::from(50)),
+                U256::from(50),
+            ));
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::voting_of { who: Address(bob().into()) },
+            )
+            .execute_returns((
+                true,
+                Vec::<(u32, bool, bool, u8, U256, U256)>::new(),
+                Address(alice().into()),
+                2u8,
+                U256::from(50),
+                (U256::zero(), U256::zero()),
+                U256::from(50),
+            ));
+    });
+}
+
+fn remark_call() -> RuntimeCall {
+    RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 200] })
+}
+
+#[test]
+fn propose_inline_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![1, 2, 3] });
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::propose_inline {
+                    bounded_call: call.encode().into(),
+                    value: U256::from(100),
+                },
+            )
+            .execute_returns(());
+
+        let (_, proposal, proposer) = pallet_democracy::PublicProps::<Runtime>::get()[0].clone();
+        assert_eq!(proposal, proposal());
+        assert_eq!(proposer, alice());
+    });
+}
+
+#[test]
+fn propose_lookup_uses_preimage_length() {
+    ExtBuilder::default().build().execute_with(|| {
+        let encoded = remark_call().encode();
+        assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(alice()), encoded.clone()));
+        let hash = <Runtime as frame_system::Config>::Hashing::hash(&encoded);
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::propose_lookup { proposal_hash: hash, value: U256::from(100) },
+            )
+            .execute_returns(());
+
+        let (_, proposal, _) = pallet_democracy::PublicProps::<Runtime>::get()[0].clone();
+        assert_eq!(proposal, Bounded::Lookup { hash, len: encoded.len() as u32 });
+    });
+}
+
+#[test]
+fn propose_lookup_fails_without_preimage() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::propose_lookup {
+                    proposal_hash: H256::repeat_byte(1),
+                    value: U256::from(100),
+                },
+            )
+            .execute_error(evm::ExitError::Other("Unable to get preimage length".into()));
+    });
+}
+
+#[test]
+fn propose_lookup_with_len_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let hash = H256::repeat_byte(1);
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::propose_lookup_with_len {
+                    proposal_hash: hash,
+                    len: 205,
+                    value: U256::from(100),
+                },
+            )
+            .execute_returns(());
+
+        let (_, proposal, _) = pallet_democracy::PublicProps::<Runtime>::get()[0].clone();
+        assert_eq!(proposal, Bounded::Lookup { hash, len: 205 });
+    });
+}
+
+#[test]
+fn second_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Democracy::propose(RuntimeOrigin::signed(alice()), proposal(), 100));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::second { proposal: 0 })
+            .execute_returns(());
+
+        assert_eq!(
+            pallet_democracy::DepositOf::<Runtime>::get(0).unwrap().0.into_inner(),
+            vec![alice(), bob()]
+        );
+    });
+}
+
+#[test]
+fn delegate_and_undelegate_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::delegate {
+                    to: Address(alice().into()),
+                    conviction: 1,
+                    balance: U256::from(50),
+                },
+            )
+            .execute_returns(());
+
+        assert!(matches!(
+            pallet_democracy::VotingOf::<Runtime>::get(bob()),
+            Voting::Delegating { balance: 50, conviction: Conviction::Locked1x, .. }
+        ));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::undelegate {})
+            .execute_returns(());
+
+        assert!(matches!(pallet_democracy::VotingOf::<Runtime>::get(bob()), Voting::Direct { .. }));
+    });
+}
+
+#[test]
+fn delegate_rejects_unknown_conviction() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::delegate {
+                    to: Address(alice().into()),
+                    conviction: 7,
+                    balance: U256::from(50),
+                },
+            )
+            .execute_error(evm::ExitError::Other("Unable to parse conviction".into()));
+    });
+}
+
+#[test]
+fn remove_other_vote_and_unlock_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let index =
+            Democracy::internal_start_referendum(proposal(), VoteThreshold::SimpleMajority, 0);
+        assert_ok!(Democracy::vote(
+            RuntimeOrigin::signed(alice()),
+            index,
+            pallet_democracy::AccountVote::Standard {
+                vote: Vote { aye: true, conviction: Conviction::Locked1x },
+                balance: 100,
+            }
+        ));
+        // the referendum has to be over before others can remove the vote
+        pallet_democracy::ReferendumInfoOf::<Runtime>::insert(
+            index,
+            ReferendumInfo::Finished { approved: false, end: 1 },
+        );
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::remove_other_vote { target: Address(alice().into()), index },
+            )
+            .execute_returns(());
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::unlock { target: Address(alice().into()) },
+            )
+            .execute_returns(());
+
+        assert!(pallet_balances::Locks::<Runtime>::get(alice()).is_empty());
+    });
+}
@@ -7,12 +7,22 @@ use frame_support::{
     traits::fungible::Inspect,
 };
 use pallet_evm::{AddressMapping, PrecompileFailure};
-use pallet_nomination_pools::BondExtra;
+use pallet_nomination_pools::{BondExtra, ClaimPermission, ConfigOp, PoolState};
+use parity_scale_codec::MaxEncodedLen;
 use precompile_utils::prelude::*;
 use sp_core::{H160, U256};
-use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_runtime::{
+    traits::{Dispatchable, StaticLookup},
+    BoundedVec, FixedPointNumber, Perbill, SaturatedConversion,
+};
 use sp_std::{marker::PhantomData, vec::Vec};
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 pub struct NominationPoolsPrecompile<Runtime>(PhantomData<Runtime>);
 
 type BalanceOf<Runtime> = <<Runtime as pallet_nomination_pools::Config>::Currency as Inspect<
@@ -22,8 +32,9 @@ type BalanceOf<Runtime> = <<Runtime as pallet_nomination_pools::Config>::Currenc
 #[precompile_utils::precompile]
 impl<Runtime> NominationPoolsPrecompile<Runtime>
 where
-    Runtime: pallet_evm::Config + pallet_nomination_pools::Config,
+    Runtime: pallet_evm::Config + pallet_nomination_pools::Config + pallet_staking::Config,
     Runtime::AccountId: Into<H160>,
+    <Runtime::RewardCounter as FixedPointNumber>::Inner: Into<U256>,
     BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
     Runtime::Lookup: StaticLookup<Source = Runtime::AccountId>,
     Runtime::RuntimeCall: From<pallet_nomination_pools::Call<Runtime>>,
@@ -41,12 +52,7 @@ where
 
     #[precompile::public("bondExtra(uint256)")]
     fn bond_extra(h: &mut impl PrecompileHandle, amount: U256) -> EvmResult<()> {
-        let extra = if amount == U256::MAX {
-            BondExtra::Rewards
-        } else {
-            let amount = Self::u256_to_amount(amount)?;
-            BondExtra::FreeBalance(amount)
-        };
+        let extra = Self::bond_extra_source(amount)?;
         let call = pallet_nomination_pools::Call::<Runtime>::bond_extra { extra };
         let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
         RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
@@ -98,10 +104,232 @@ where
         Ok(())
     }
 
+    #[precompile::public("create(uint256,address,address,address)")]
+    fn create(
+        h: &mut impl PrecompileHandle,
+        amount: U256,
+        root: Address,
+        nominator: Address,
+        bouncer: Address,
+    ) -> EvmResult<()> {
+        let amount = Self::u256_to_amount(amount)?;
+        let call = pallet_nomination_pools::Call::<Runtime>::create {
+            amount,
+            root: Self::lookup_source(root),
+            nominator: Self::lookup_source(nominator),
+            bouncer: Self::lookup_source(bouncer),
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("createWithPoolId(uint256,address,address,address,uint32)")]
+    fn create_with_pool_id(
+        h: &mut impl PrecompileHandle,
+        amount: U256,
+        root: Address,
+        nominator: Address,
+        bouncer: Address,
+        pool_id: u32,
+    ) -> EvmResult<()> {
+        let amount = Self::u256_to_amount(amount)?;
+        let call = pallet_nomination_pools::Call::<Runtime>::create_with_pool_id {
+            amount,
+            root: Self::lookup_source(root),
+            nominator: Self::lookup_source(nominator),
+            bouncer: Self::lookup_source(bouncer),
+            pool_id,
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("nominate(uint32,address[])")]
+    fn nominate(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+        validators: Vec<Address>,
+    ) -> EvmResult<()> {
+        let validators = validators
+            .into_iter()
+            .map(|validator| Runtime::AddressMapping::into_account_id(validator.0))
+            .collect();
+        let call = pallet_nomination_pools::Call::<Runtime>::nominate { pool_id, validators };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("chill(uint32)")]
+    fn chill(h: &mut impl PrecompileHandle, pool_id: u32) -> EvmResult<()> {
+        let call = pallet_nomination_pools::Call::<Runtime>::chill { pool_id };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// `state` is 0 for `Open`, 1 for `Blocked` and 2 for `Destroying`.
+    #[precompile::public("setState(uint32,uint8)")]
+    fn set_state(h: &mut impl PrecompileHandle, pool_id: u32, state: u8) -> EvmResult<()> {
+        let state = match state {
+            0 => PoolState::Open,
+            1 => PoolState::Blocked,
+            2 => PoolState::Destroying,
+            _ => return Err(RevertReason::custom("Unknown pool state").in_field("state").into()),
+        };
+        let call = pallet_nomination_pools::Call::<Runtime>::set_state { pool_id, state };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("setMetadata(uint32,bytes)")]
+    fn set_metadata(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+        metadata: UnboundedBytes,
+    ) -> EvmResult<()> {
+        let call = pallet_nomination_pools::Call::<Runtime>::set_metadata {
+            pool_id,
+            metadata: metadata.into(),
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// `commission` is in parts per billion, i.e. 1_000_000_000 is 100%, and is paid to `payee`.
+    /// A zero commission removes the commission of the pool.
+    #[precompile::public("setCommission(uint32,uint32,address)")]
+    fn set_commission(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+        commission: u32,
+        payee: Address,
+    ) -> EvmResult<()> {
+        let new_commission = (commission != 0).then(|| {
+            (Perbill::from_parts(commission), Runtime::AddressMapping::into_account_id(payee.0))
+        });
+        let call =
+            pallet_nomination_pools::Call::<Runtime>::set_commission { pool_id, new_commission };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// `max_commission` is in parts per billion.
+    #[precompile::public("setCommissionMax(uint32,uint32)")]
+    fn set_commission_max(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+        max_commission: u32,
+    ) -> EvmResult<()> {
+        let call = pallet_nomination_pools::Call::<Runtime>::set_commission_max {
+            pool_id,
+            max_commission: Perbill::from_parts(max_commission),
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("claimCommission(uint32)")]
+    fn claim_commission(h: &mut impl PrecompileHandle, pool_id: u32) -> EvmResult<()> {
+        let call = pallet_nomination_pools::Call::<Runtime>::claim_commission { pool_id };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// Each role is changed according to its op: 0 keeps the role, 1 sets it to the given
+    /// account and 2 removes it.
+    #[precompile::public("updateRoles(uint32,uint8,address,uint8,address,uint8,address)")]
+    #[allow(clippy::too_many_arguments)]
+    fn update_roles(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+        root_op: u8,
+        root: Address,
+        nominator_op: u8,
+        nominator: Address,
+        bouncer_op: u8,
+        bouncer: Address,
+    ) -> EvmResult<()> {
+        let call = pallet_nomination_pools::Call::<Runtime>::update_roles {
+            pool_id,
+            new_root: Self::role_op(root_op, root).in_field("rootOp")?,
+            new_nominator: Self::role_op(nominator_op, nominator).in_field("nominatorOp")?,
+            new_bouncer: Self::role_op(bouncer_op, bouncer).in_field("bouncerOp")?,
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// `amount` is the same as in `bondExtra`.
+    #[precompile::public("bondExtraOther(address,uint256)")]
+    fn bond_extra_other(
+        h: &mut impl PrecompileHandle,
+        member: Address,
+        amount: U256,
+    ) -> EvmResult<()> {
+        let extra = Self::bond_extra_source(amount)?;
+        let call = pallet_nomination_pools::Call::<Runtime>::bond_extra_other {
+            member: Self::lookup_source(member),
+            extra,
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// `permission` is 0 for `Permissioned`, 1 for `PermissionlessCompound`, 2 for
+    /// `PermissionlessWithdraw` and 3 for `PermissionlessAll`.
+    #[precompile::public("setClaimPermission(uint8)")]
+    fn set_claim_permission(h: &mut impl PrecompileHandle, permission: u8) -> EvmResult<()> {
+        let permission = match permission {
+            0 => ClaimPermission::Permissioned,
+            1 => ClaimPermission::PermissionlessCompound,
+            2 => ClaimPermission::PermissionlessWithdraw,
+            3 => ClaimPermission::PermissionlessAll,
+            _ => {
+                return Err(RevertReason::custom("Unknown claim permission")
+                    .in_field("permission")
+                    .into())
+            },
+        };
+        let call = pallet_nomination_pools::Call::<Runtime>::set_claim_permission { permission };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
     #[precompile::public("pendingRewards(address)")]
     #[precompile::view]
-    fn pending_rewards(_: &mut impl PrecompileHandle, who: Address) -> EvmResult<U256> {
-        // TODO: record gas
+    fn pending_rewards(h: &mut impl PrecompileHandle, who: Address) -> EvmResult<U256> {
+        // Storage item: PoolMembers: PoolMember
+        h.record_db_read::<Runtime>(
+            Self::account_key_len()
+                + pallet_nomination_pools::PoolMember::<Runtime>::max_encoded_len(),
+        )?;
+        // Storage item: BondedPools: BondedPoolInner
+        h.record_db_read::<Runtime>(
+            Self::pool_key_len()
+                + pallet_nomination_pools::BondedPoolInner::<Runtime>::max_encoded_len(),
+        )?;
+        // Storage item: RewardPools: RewardPool
+        h.record_db_read::<Runtime>(
+            Self::pool_key_len()
+                + pallet_nomination_pools::RewardPool::<Runtime>::max_encoded_len(),
+        )?;
+        // Storage item: System::Account: AccountInfo of the reward account, keyed by
+        // `Blake2_128Concat`
+        h.record_db_read::<Runtime>(
+            16 + Runtime::AccountId::max_encoded_len()
+                + frame_system::AccountInfo::<Runtime::Nonce, Runtime::AccountData>::max_encoded_len(),
+        )?;
         let who = Runtime::AddressMapping::into_account_id(who.0);
         let amount = pallet_nomination_pools::Pallet::<Runtime>::api_pending_rewards(who)
             .map(<_>::into)
@@ -109,24 +337,178 @@ where
         Ok(amount)
     }
 
+    /// Returns the member counter, the points, the state and the roles as
+    /// `(depositor, root, nominator, bouncer)` of the pool. Unset roles are the zero address.
     #[precompile::public("bondedPools(uint32)")]
     #[precompile::view]
-    // TODO: return value is to be discussed
-    fn bonded_pools(_: &mut impl PrecompileHandle, pool_id: u32) -> EvmResult<(u32, U256)> {
-        // TODO: record gas
-        let bonded_pool = pallet_nomination_pools::BondedPool::<Runtime>::get(pool_id)
-            .ok_or_else(|| Self::custom_err("Unable to get bonded pool"))?;
-        let pallet_nomination_pools::BondedPoolInner { member_counter, points, .. } = *bonded_pool;
-        Ok((member_counter, points.into()))
+    #[allow(clippy::type_complexity)]
+    fn bonded_pools(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+    ) -> EvmResult<(u32, U256, u8, (Address, Address, Address, Address))> {
+        let pallet_nomination_pools::BondedPoolInner {
+            member_counter, points, state, roles, ..
+        } = Self::bonded_pool(h, pool_id)?;
+        Ok((
+            member_counter,
+            points.into(),
+            Self::state_to_u8(state),
+            Self::roles_to_addresses(roles),
+        ))
+    }
+
+    #[precompile::public("poolState(uint32)")]
+    #[precompile::view]
+    fn pool_state(h: &mut impl PrecompileHandle, pool_id: u32) -> EvmResult<u8> {
+        let pool = Self::bonded_pool(h, pool_id)?;
+        Ok(Self::state_to_u8(pool.state))
+    }
+
+    /// Returns the roles of the pool as `(depositor, root, nominator, bouncer)`. Unset roles are
+    /// the zero address.
+    #[precompile::public("poolRoles(uint32)")]
+    #[precompile::view]
+    fn pool_roles(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+    ) -> EvmResult<(Address, Address, Address, Address)> {
+        let pool = Self::bonded_pool(h, pool_id)?;
+        Ok(Self::roles_to_addresses(pool.roles))
+    }
+
+    /// Returns the current commission and its payee, the max commission, and the max increase
+    /// and min delay in blocks of the change rate. Commissions are in parts per billion; a pool
+    /// without a max commission or change rate returns 100% and a zero delay.
+    #[precompile::public("poolCommission(uint32)")]
+    #[precompile::view]
+    fn pool_commission(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+    ) -> EvmResult<(u32, Address, u32, u32, u32)> {
+        let pallet_nomination_pools::Commission { current, max, change_rate, .. } =
+            Self::bonded_pool(h, pool_id)?.commission;
+        let (commission, payee) = current
+            .map(|(commission, payee)| (commission.deconstruct(), Address(payee.into())))
+            .unwrap_or((0, Address(H160::zero())));
+        let (max_increase, min_delay) = change_rate
+            .map(|rate| (rate.max_increase, rate.min_delay.saturated_into::<u32>()))
+            .unwrap_or((Perbill::one(), 0));
+        Ok((
+            commission,
+            payee,
+            max.unwrap_or_else(Perbill::one).deconstruct(),
+            max_increase.deconstruct(),
+            min_delay,
+        ))
+    }
+
+    #[precompile::public("poolMetadata(uint32)")]
+    #[precompile::view]
+    fn pool_metadata(h: &mut impl PrecompileHandle, pool_id: u32) -> EvmResult<UnboundedBytes> {
+        // Storage item: Metadata: BoundedVec<u8, MaxMetadataLen>
+        h.record_db_read::<Runtime>(
+            Self::pool_key_len() + BoundedVec::<u8, Runtime::MaxMetadataLen>::max_encoded_len(),
+        )?;
+        let metadata = pallet_nomination_pools::Metadata::<Runtime>::get(pool_id);
+        Ok(metadata.into_inner().into())
+    }
+
+    /// Returns the last recorded reward counter as a fixed point number with 18 decimals, the
+    /// last recorded total payouts, the total claimed rewards and the pending and claimed
+    /// commission of the pool.
+    #[precompile::public("rewardPool(uint32)")]
+    #[precompile::view]
+    fn reward_pool(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+    ) -> EvmResult<(U256, U256, U256, U256, U256)> {
+        // Storage item: RewardPools: RewardPool
+        h.record_db_read::<Runtime>(
+            Self::pool_key_len()
+                + pallet_nomination_pools::RewardPool::<Runtime>::max_encoded_len(),
+        )?;
+        let pallet_nomination_pools::RewardPool {
+            last_recorded_reward_counter,
+            last_recorded_total_payouts,
+            total_rewards_claimed,
+            total_commission_pending,
+            total_commission_claimed,
+        } = pallet_nomination_pools::RewardPools::<Runtime>::get(pool_id)
+            .ok_or_else(|| Self::custom_err("Unable to get reward pool"))?;
+        Ok((
+            last_recorded_reward_counter.into_inner().into(),
+            last_recorded_total_payouts.into(),
+            total_rewards_claimed.into(),
+            total_commission_pending.into(),
+            total_commission_claimed.into(),
+        ))
+    }
+
+    /// Returns the `(points, balance)` of the unbonding pool without an era and the
+    /// `(era, points, balance)` of the unbonding pools per era.
+    #[precompile::public("subPools(uint32)")]
+    #[precompile::view]
+    #[allow(clippy::type_complexity)]
+    fn sub_pools(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+    ) -> EvmResult<((U256, U256), Vec<(u32, U256, U256)>)> {
+        // Storage item: SubPoolsStorage: SubPools
+        h.record_db_read::<Runtime>(
+            Self::pool_key_len() + pallet_nomination_pools::SubPools::<Runtime>::max_encoded_len(),
+        )?;
+        let pallet_nomination_pools::SubPools { no_era, with_era } =
+            pallet_nomination_pools::SubPoolsStorage::<Runtime>::get(pool_id)
+                .ok_or_else(|| Self::custom_err("Unable to get sub pools"))?;
+        Ok((
+            (no_era.points.into(), no_era.balance.into()),
+            with_era
+                .into_iter()
+                .map(|(era, pool)| (era, pool.points.into(), pool.balance.into()))
+                .collect(),
+        ))
+    }
+
+    /// Returns the balance the `points` of the pool are worth.
+    #[precompile::public("pointsToBalance(uint32,uint256)")]
+    #[precompile::view]
+    fn points_to_balance(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+        points: U256,
+    ) -> EvmResult<U256> {
+        let points = Self::u256_to_amount(points).in_field("points")?;
+        Self::record_pool_stake_read(h)?;
+        Ok(pallet_nomination_pools::Pallet::<Runtime>::api_points_to_balance(pool_id, points)
+            .into())
+    }
+
+    /// Returns the points that `new_funds` would be issued when bonded to the pool.
+    #[precompile::public("balanceToPoints(uint32,uint256)")]
+    #[precompile::view]
+    fn balance_to_points(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+        new_funds: U256,
+    ) -> EvmResult<U256> {
+        let new_funds = Self::u256_to_amount(new_funds).in_field("newFunds")?;
+        Self::record_pool_stake_read(h)?;
+        Ok(pallet_nomination_pools::Pallet::<Runtime>::api_balance_to_points(pool_id, new_funds)
+            .into())
     }
 
     #[precompile::public("poolMembers(address)")]
     #[precompile::view]
     #[allow(clippy::type_complexity)]
     fn pool_members(
-        _: &mut impl PrecompileHandle,
+        h: &mut impl PrecompileHandle,
         address: Address,
     ) -> EvmResult<(u32, U256, Vec<(u32, U256)>)> {
+        // Storage item: PoolMembers: PoolMember, with at most `MaxUnbonding` unbonding eras
+        h.record_db_read::<Runtime>(
+            Self::account_key_len()
+                + pallet_nomination_pools::PoolMember::<Runtime>::max_encoded_len(),
+        )?;
         let address = Runtime::AddressMapping::into_account_id(address.0);
         let pallet_nomination_pools::PoolMember { pool_id, points, unbonding_eras, .. } =
             pallet_nomination_pools::PoolMembers::<Runtime>::get(address)
@@ -142,6 +524,93 @@ where
         ))
     }
 
+    fn bonded_pool(
+        h: &mut impl PrecompileHandle,
+        pool_id: u32,
+    ) -> EvmResult<pallet_nomination_pools::BondedPoolInner<Runtime>> {
+        // Storage item: BondedPools: BondedPoolInner
+        h.record_db_read::<Runtime>(
+            Self::pool_key_len()
+                + pallet_nomination_pools::BondedPoolInner::<Runtime>::max_encoded_len(),
+        )?;
+        pallet_nomination_pools::BondedPools::<Runtime>::get(pool_id)
+            .ok_or_else(|| Self::custom_err("Unable to get bonded pool"))
+    }
+
+    /// Records the reads of the pool and of the stake of its bonded account.
+    fn record_pool_stake_read(h: &mut impl PrecompileHandle) -> EvmResult {
+        // Storage item: BondedPools: BondedPoolInner
+        h.record_db_read::<Runtime>(
+            Self::pool_key_len()
+                + pallet_nomination_pools::BondedPoolInner::<Runtime>::max_encoded_len(),
+        )?;
+        // Storage item: Staking::Bonded: AccountId, keyed by `Twox64Concat`
+        h.record_db_read::<Runtime>(
+            Self::account_key_len() + Runtime::AccountId::max_encoded_len(),
+        )?;
+        // Storage item: Staking::Ledger: StakingLedger, keyed by `Blake2_128Concat`
+        h.record_db_read::<Runtime>(
+            16 + Runtime::AccountId::max_encoded_len()
+                + pallet_staking::StakingLedger::<Runtime>::max_encoded_len(),
+        )?;
+        Ok(())
+    }
+
+    /// `Open` is 0, `Blocked` is 1 and `Destroying` is 2, the same as in `setState`.
+    fn state_to_u8(state: PoolState) -> u8 {
+        match state {
+            PoolState::Open => 0,
+            PoolState::Blocked => 1,
+            PoolState::Destroying => 2,
+        }
+    }
+
+    fn roles_to_addresses(
+        roles: pallet_nomination_pools::PoolRoles<Runtime::AccountId>,
+    ) -> (Address, Address, Address, Address) {
+        let address = |account: Option<Runtime::AccountId>| {
+            Address(account.map(Into::into).unwrap_or_default())
+        };
+        (
+            Address(roles.depositor.into()),
+            address(roles.root),
+            address(roles.nominator),
+            address(roles.bouncer),
+        )
+    }
+
+    /// `U256::MAX` bonds the pending rewards, any other amount is bonded from the free balance.
+    fn bond_extra_source(amount: U256) -> MayRevert<BondExtra<BalanceOf<Runtime>>> {
+        if amount == U256::MAX {
+            Ok(BondExtra::Rewards)
+        } else {
+            Ok(BondExtra::FreeBalance(Self::u256_to_amount(amount)?))
+        }
+    }
+
+    fn role_op(op: u8, account: Address) -> MayRevert<ConfigOp<Runtime::AccountId>> {
+        match op {
+            0 => Ok(ConfigOp::Noop),
+            1 => Ok(ConfigOp::Set(Runtime::AddressMapping::into_account_id(account.0))),
+            2 => Ok(ConfigOp::Remove),
+            _ => Err(RevertReason::custom("Unknown role op").into()),
+        }
+    }
+
+    fn lookup_source(address: Address) -> <Runtime::Lookup as StaticLookup>::Source {
+        Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(address.0))
+    }
+
+    /// The length of a storage map key hashed with `Twox64Concat` over an account.
+    fn account_key_len() -> usize {
+        8 + Runtime::AccountId::max_encoded_len()
+    }
+
+    /// The length of a storage map key hashed with `Twox64Concat` over a pool id.
+    fn pool_key_len() -> usize {
+        8 + pallet_nomination_pools::PoolId::max_encoded_len()
+    }
+
     fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
         value
             .try_into()
@@ -0,0 +1,102 @@
+use crate::NominationPoolsPrecompile;
+use frame_election_provider_support::{onchain, SequentialPhragmen};
+use frame_support::{
+    parameter_types,
+    traits::{ConstU32, ConstU8},
+    PalletId,
+};
+use sp_core::U256;
+use sp_runtime::{traits::Convert, FixedU128, Perbill};
+use sp_staking::currency_to_vote::SaturatingCurrencyToVote;
+
+precompile_test_utils::mock_runtime! {
+    precompile: NominationPoolsPrecompile<Runtime>,
+    address: 2006,
+    pallets: {
+        Staking: pallet_staking,
+        Pools: pallet_nomination_pools,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000)],
+}
+
+parameter_types! {
+    pub const SessionsPerEra: sp_staking::SessionIndex = 6;
+    pub const BondingDuration: sp_staking::EraIndex = 3;
+    pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
+}
+
+pub struct OnChainSeqPhragmen;
+impl onchain::Config for OnChainSeqPhragmen {
+    type System = Runtime;
+    type Solver = SequentialPhragmen<AccountId, Perbill>;
+    type DataProvider = Staking;
+    type WeightInfo = ();
+    type MaxWinners = ConstU32<100>;
+    type Bounds = ();
+}
+
+impl pallet_staking::Config for Runtime {
+    type NominationsQuota = pallet_staking::FixedNominationsQuota<16>;
+    type Currency = Balances;
+    type CurrencyBalance = Balance;
+    type UnixTime = Timestamp;
+    type CurrencyToVote = SaturatingCurrencyToVote;
+    type RewardRemainder = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Slash = ();
+    type Reward = ();
+    type SessionsPerEra = SessionsPerEra;
+    type BondingDuration = BondingDuration;
+    type SlashDeferDuration = ();
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type SessionInterface = ();
+    type EraPayout = ();
+    type NextNewSession = ();
+    type MaxExposurePageSize = ConstU32<64>;
+    type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
+    type ElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
+    type GenesisElectionProvider = Self::ElectionProvider;
+    type VoterList = pallet_staking::UseNominatorsAndValidatorsMap<Self>;
+    type TargetList = pallet_staking::UseValidatorsMap<Self>;
+    type MaxUnlockingChunks = ConstU32<32>;
+    type HistoryDepth = ConstU32<84>;
+    type EventListeners = Pools;
+    type MaxControllersInDeprecationBatch = ConstU32<100>;
+    type WeightInfo = ();
+    type BenchmarkingConfig = pallet_staking::TestBenchmarkingConfig;
+}
+
+parameter_types! {
+    pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
+}
+
+pub struct BalanceToU256;
+impl Convert<Balance, U256> for BalanceToU256 {
+    fn convert(balance: Balance) -> U256 {
+        U256::from(balance)
+    }
+}
+
+pub struct U256ToBalance;
+impl Convert<U256, Balance> for U256ToBalance {
+    fn convert(n: U256) -> Balance {
+        n.try_into().unwrap_or(Balance::MAX)
+    }
+}
+
+impl pallet_nomination_pools::Config for Runtime {
+    type WeightInfo = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RuntimeFreezeReason = RuntimeFreezeReason;
+    type RewardCounter = FixedU128;
+    type BalanceToU256 = BalanceToU256;
+    type U256ToBalance = U256ToBalance;
+    type Staking = Staking;
+    type PostUnbondingPoolsWindow = ConstU32<4>;
+    type MaxMetadataLen = ConstU32<256>;
+    type MaxUnbonding = ConstU32<8>;
+    type PalletId = PoolsPalletId;
+    type MaxPointsToBalance = ConstU8<10>;
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+}
@@ -0,0 +1,488 @@
+use crate::mock::*;
+use frame_support::assert_ok;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+use sp_runtime::Perbill;
+
+type PCall = crate::NominationPoolsPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+fn create_pool() {
+    assert_ok!(Pools::create(RuntimeOrigin::signed(alice()), 1000, alice(), alice(), alice()));
+}
+
+#[test]
+fn pending_rewards_records_gas() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::pending_rewards { who: Address(alice().into()) },
+            )
+            .expect_cost(4 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .expect_no_logs()
+            .execute_returns(U256::zero());
+    });
+}
+
+#[test]
+fn bonded_pools_records_gas() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::bonded_pools { pool_id: 1 })
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .expect_no_logs()
+            .execute_returns((
+                1u32,
+                U256::from(1000),
+                0u8,
+                (
+                    Address(alice().into()),
+                    Address(alice().into()),
+                    Address(alice().into()),
+                    Address(alice().into()),
+                ),
+            ));
+    });
+}
+
+#[test]
+fn pool_members_records_gas() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::pool_members { address: Address(alice().into()) },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .expect_no_logs()
+            .execute_returns((1u32, U256::from(1000), Vec::<(u32, U256)>::new()));
+    });
+}
+
+#[test]
+fn pool_members_charges_gas_for_unknown_member() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::pool_members { address: Address(bob().into()) },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_error(evm::ExitError::Other("Unable to get pool members".into()));
+    });
+}
+
+#[test]
+fn create_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::create {
+                    amount: U256::from(1000),
+                    root: Address(alice().into()),
+                    nominator: Address(bob().into()),
+                    bouncer: Address(bob().into()),
+                },
+            )
+            .execute_returns(());
+
+        let pool = pallet_nomination_pools::BondedPools::<Runtime>::get(1).unwrap();
+        assert_eq!(pool.roles.depositor, alice());
+        assert_eq!(pool.roles.nominator, Some(bob()));
+        assert_eq!(
+            pallet_nomination_pools::PoolMembers::<Runtime>::get(alice()).unwrap().points,
+            1000
+        );
+    });
+}
+
+#[test]
+fn create_with_pool_id_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::create_with_pool_id {
+                    amount: U256::from(1000),
+                    root: Address(bob().into()),
+                    nominator: Address(bob().into()),
+                    bouncer: Address(bob().into()),
+                    pool_id: 2,
+                },
+            )
+            .execute_returns(());
+
+        assert!(pallet_nomination_pools::BondedPools::<Runtime>::contains_key(2));
+    });
+}
+
+#[test]
+fn nominate_and_chill_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+        assert_ok!(Staking::bond(
+            RuntimeOrigin::signed(bob()),
+            1000,
+            pallet_staking::RewardDestination::Staked
+        ));
+        assert_ok!(Staking::validate(RuntimeOrigin::signed(bob()), Default::default()));
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::nominate { pool_id: 1, validators: vec![Address(bob().into())] },
+            )
+            .execute_returns(());
+
+        let bonded_account = Pools::create_bonded_account(1);
+        assert_eq!(
+            pallet_staking::Nominators::<Runtime>::get(bonded_account)
+                .unwrap()
+                .targets
+                .into_inner(),
+            vec![bob()]
+        );
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::chill { pool_id: 1 })
+            .execute_returns(());
+
+        assert!(!pallet_staking::Nominators::<Runtime>::contains_key(bonded_account));
+    });
+}
+
+#[test]
+fn set_state_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::set_state { pool_id: 1, state: 1 })
+            .execute_returns(());
+
+        assert_eq!(
+            pallet_nomination_pools::BondedPools::<Runtime>::get(1).unwrap().state,
+            pallet_nomination_pools::PoolState::Blocked
+        );
+    });
+}
+
+#[test]
+fn set_state_rejects_unknown_state() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::set_state { pool_id: 1, state: 3 })
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("Unknown pool state")
+            });
+    });
+}
+
+#[test]
+fn set_metadata_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::set_metadata { pool_id: 1, metadata: b"club".to_vec().into() },
+            )
+            .execute_returns(());
+
+        assert_eq!(pallet_nomination_pools::Metadata::<Runtime>::get(1).into_inner(), b"club");
+    });
+}
+
+#[test]
+fn commission_calls_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::set_commission_max { pool_id: 1, max_commission: 500_000_000 },
+            )
+            .execute_returns(());
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::set_commission {
+                    pool_id: 1,
+                    commission: 100_000_000,
+                    payee: Address(bob().into()),
+                },
+            )
+            .execute_returns(());
+
+        let commission =
+            pallet_nomination_pools::BondedPools::<Runtime>::get(1).unwrap().commission;
+        assert_eq!(commission.max, Some(Perbill::from_percent(50)));
+        assert_eq!(commission.current, Some((Perbill::from_percent(10), bob())));
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::set_commission { pool_id: 1, commission: 0, payee: Address(H160::zero()) },
+            )
+            .execute_returns(());
+
+        let commission =
+            pallet_nomination_pools::BondedPools::<Runtime>::get(1).unwrap().commission;
+        assert_eq!(commission.current, None);
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::claim_commission { pool_id: 1 })
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("NoPendingCommission")
+            });
+    });
+}
+
+#[test]
+fn update_roles_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::update_roles {
+                    pool_id: 1,
+                    root_op: 0,
+                    root: Address(H160::zero()),
+                    nominator_op: 1,
+                    nominator: Address(bob().into()),
+                    bouncer_op: 2,
+                    bouncer: Address(H160::zero()),
+                },
+            )
+            .execute_returns(());
+
+        let roles = pallet_nomination_pools::BondedPools::<Runtime>::get(1).unwrap().roles;
+        assert_eq!(roles.root, Some(alice()));
+        assert_eq!(roles.nominator, Some(bob()));
+        assert_eq!(roles.bouncer, None);
+    });
+}
+
+#[test]
+fn update_roles_rejects_unknown_op() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::update_roles {
+                    pool_id: 1,
+                    root_op: 0,
+                    root: Address(H160::zero()),
+                    nominator_op: 3,
+                    nominator: Address(H160::zero()),
+                    bouncer_op: 0,
+                    bouncer: Address(H160::zero()),
+                },
+            )
+            .execute_reverts(|output| String::from_utf8_lossy(output).contains("Unknown role op"));
+    });
+}
+
+#[test]
+fn bond_extra_other_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::set_claim_permission { permission: 3 },
+            )
+            .execute_returns(());
+        assert_eq!(
+            pallet_nomination_pools::ClaimPermissions::<Runtime>::get(alice()),
+            pallet_nomination_pools::ClaimPermission::PermissionlessAll
+        );
+
+        // only the member can bond extra from its free balance, others can only compound rewards
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::bond_extra_other {
+                    member: Address(alice().into()),
+                    amount: U256::from(500),
+                },
+            )
+            .execute_returns(());
+
+        assert_eq!(
+            pallet_nomination_pools::PoolMembers::<Runtime>::get(alice()).unwrap().points,
+            1500
+        );
+    });
+}
+
+#[test]
+fn set_claim_permission_rejects_unknown_permission() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::set_claim_permission { permission: 4 },
+            )
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("Unknown claim permission")
+            });
+    });
+}
+
+#[test]
+fn pool_views_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+        assert_ok!(Pools::set_metadata(RuntimeOrigin::signed(alice()), 1, b"club".to_vec()));
+        assert_ok!(Pools::set_state(
+            RuntimeOrigin::signed(alice()),
+            1,
+            pallet_nomination_pools::PoolState::Blocked
+        ));
+        assert_ok!(Pools::update_roles(
+            RuntimeOrigin::signed(alice()),
+            1,
+            pallet_nomination_pools::ConfigOp::Noop,
+            pallet_nomination_pools::ConfigOp::Set(bob()),
+            pallet_nomination_pools::ConfigOp::Remove,
+        ));
+        assert_ok!(Pools::set_commission_max(
+            RuntimeOrigin::signed(alice()),
+            1,
+            Perbill::from_percent(50)
+        ));
+        assert_ok!(Pools::set_commission(
+            RuntimeOrigin::signed(alice()),
+            1,
+            Some((Perbill::from_percent(10), bob()))
+        ));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::pool_state { pool_id: 1 })
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(1u8);
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::pool_roles { pool_id: 1 })
+            .execute_returns((
+                Address(alice().into()),
+                Address(alice().into()),
+                Address(bob().into()),
+                Address(H160::zero()),
+            ));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::pool_commission { pool_id: 1 })
+            .execute_returns((
+                100_000_000u32,
+                Address(bob().into()),
+                500_000_000u32,
+                1_000_000_000u32,
+                0u32,
+            ));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::pool_metadata { pool_id: 1 })
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(UnboundedBytes::from(b"club".to_vec()));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::reward_pool { pool_id: 1 })
+            .execute_returns((
+                U256::zero(),
+                U256::zero(),
+                U256::zero(),
+                U256::zero(),
+                U256::zero(),
+            ));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::sub_pools { pool_id: 1 })
+            .execute_error(evm::ExitError::Other("Unable to get sub pools".into()));
+    });
+}
+
+#[test]
+fn sub_pools_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+        assert_ok!(Pools::join(RuntimeOrigin::signed(bob()), 500, 1));
+        assert_ok!(Pools::unbond(RuntimeOrigin::signed(bob()), bob(), 200));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::sub_pools { pool_id: 1 })
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((
+                (U256::zero(), U256::zero()),
+                vec![(3u32, U256::from(200), U256::from(200))],
+            ));
+    });
+}
+
+#[test]
+fn points_and_balance_conversions_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        create_pool();
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::points_to_balance { pool_id: 1, points: U256::from(500) },
+            )
+            .expect_cost(3 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(U256::from(500));
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::balance_to_points { pool_id: 1, new_funds: U256::from(500) },
+            )
+            .expect_cost(3 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(U256::from(500));
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::points_to_balance { pool_id: 2, points: U256::from(500) },
+            )
+            .execute_returns(U256::zero());
+    });
+}
@@ -4,14 +4,25 @@
 use fp_evm::PrecompileHandle;
 use frame_support::{
     dispatch::{GetDispatchInfo, PostDispatchInfo},
-    traits::IsType,
+    traits::{Currency, IsType, QueryPreimage},
 };
-use pallet_evm::AddressMapping;
+use pallet_evm::{AddressMapping, PrecompileFailure};
+use parity_scale_codec::{Compact, MaxEncodedLen};
 use precompile_utils::prelude::*;
 use sp_core::{H160, H256};
-use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_runtime::traits::{Dispatchable, Hash, StaticLookup};
 use sp_std::{marker::PhantomData, vec::Vec};
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_preimage::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
 pub struct PreimagePrecompile<Runtime>(PhantomData<Runtime>);
 
 #[precompile_utils::precompile]
@@ -25,11 +36,89 @@ where
     <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 {
-    #[precompile::public("notePreimage(uint8[])")]
-    fn note_preimage(h: &mut impl PrecompileHandle, bytes: Vec<u8>) -> EvmResult<()> {
+    /// Notes `bytes` as a preimage and returns its hash.
+    #[precompile::public("notePreimage(bytes)")]
+    fn note_preimage(h: &mut impl PrecompileHandle, bytes: UnboundedBytes) -> EvmResult<H256> {
+        let bytes: Vec<u8> = bytes.into();
+        let hash = Runtime::Hashing::hash(&bytes).into();
+
         let call = pallet_preimage::Call::<Runtime>::note_preimage { bytes };
         let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
         RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(hash)
+    }
+
+    /// Clears an unrequested preimage and releases the deposit of the caller who noted it.
+    #[precompile::public("unnotePreimage(bytes32)")]
+    fn unnote_preimage(h: &mut impl PrecompileHandle, hash: H256) -> EvmResult<()> {
+        let call = pallet_preimage::Call::<Runtime>::unnote_preimage { hash: hash.into() };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
         Ok(())
     }
+
+    #[precompile::public("requestPreimage(bytes32)")]
+    fn request_preimage(h: &mut impl PrecompileHandle, hash: H256) -> EvmResult<()> {
+        let call = pallet_preimage::Call::<Runtime>::request_preimage { hash: hash.into() };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("unrequestPreimage(bytes32)")]
+    fn unrequest_preimage(h: &mut impl PrecompileHandle, hash: H256) -> EvmResult<()> {
+        let call = pallet_preimage::Call::<Runtime>::unrequest_preimage { hash: hash.into() };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// Returns whether the preimage is noted, whether it is requested, and its length, which is
+    /// 0 while the preimage isn't noted.
+    #[precompile::public("preimageStatus(bytes32)")]
+    #[precompile::view]
+    fn preimage_status(h: &mut impl PrecompileHandle, hash: H256) -> EvmResult<(bool, bool, u32)> {
+        // Storage items: RequestStatusFor, read twice, and the legacy StatusFor
+        for _ in 0..3 {
+            h.record_db_read::<Runtime>(Self::preimage_status_len())?;
+        }
+        let hash = hash.into();
+        let len = <pallet_preimage::Pallet<Runtime> as QueryPreimage>::len(&hash);
+        let requested = <pallet_preimage::Pallet<Runtime> as QueryPreimage>::is_requested(&hash);
+        Ok((len.is_some(), requested, len.unwrap_or_default()))
+    }
+
+    #[precompile::public("preimageFor(bytes32)")]
+    #[precompile::view]
+    fn preimage_for(h: &mut impl PrecompileHandle, hash: H256) -> EvmResult<UnboundedBytes> {
+        // Storage items: RequestStatusFor and the legacy StatusFor
+        h.record_db_read::<Runtime>(Self::preimage_status_len())?;
+        h.record_db_read::<Runtime>(Self::preimage_status_len())?;
+        let hash = hash.into();
+        let len = <pallet_preimage::Pallet<Runtime> as QueryPreimage>::len(&hash)
+            .ok_or_else(|| Self::custom_err("Unable to get preimage"))?;
+
+        // Storage item: PreimageFor: BoundedVec<u8, MAX_SIZE>
+        h.record_db_read::<Runtime>(
+            H256::max_encoded_len()
+                + u32::max_encoded_len()
+                + Compact::<u32>::max_encoded_len()
+                + len as usize,
+        )?;
+        let bytes = <pallet_preimage::Pallet<Runtime> as QueryPreimage>::fetch(&hash, Some(len))
+            .map_err(|_| Self::custom_err("Unable to get preimage"))?;
+        Ok(bytes.into_owned().into())
+    }
+
+    /// The length of a preimage status entry, bounding both `RequestStatusFor` and the legacy
+    /// `StatusFor`, whose deposit is a balance.
+    fn preimage_status_len() -> usize {
+        H256::max_encoded_len()
+            + pallet_preimage::RequestStatus::<Runtime::AccountId, Runtime::Consideration>::max_encoded_len()
+            + BalanceOf::<Runtime>::max_encoded_len()
+    }
+
+    fn custom_err(reason: &'static str) -> PrecompileFailure {
+        PrecompileFailure::Error { exit_status: evm::ExitError::Other(reason.into()) }
+    }
 }
@@ -0,0 +1,33 @@
+use crate::PreimagePrecompile;
+use frame_support::{
+    parameter_types,
+    traits::{fungible::HoldConsideration, ConstU128, LinearStoragePrice},
+};
+use frame_system::EnsureSigned;
+
+precompile_test_utils::mock_runtime! {
+    precompile: PreimagePrecompile<Runtime>,
+    address: 2003,
+    pallets: {
+        Preimage: pallet_preimage,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000), (charlie(), 1_000_000)],
+}
+
+parameter_types! {
+    pub const PreimageHoldReason: RuntimeHoldReason =
+        RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+    type WeightInfo = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type ManagerOrigin = EnsureSigned<AccountId>;
+    type Consideration = HoldConsideration<
+        AccountId,
+        Balances,
+        PreimageHoldReason,
+        LinearStoragePrice<ConstU128<10>, ConstU128<1>, Balance>,
+    >;
+}
@@ -0,0 +1,98 @@
+use crate::mock::*;
+use frame_support::traits::fungible::InspectHold;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, H256};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+type PCall = crate::PreimagePrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+#[test]
+fn note_and_unnote_preimage_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let hash = BlakeTwo256::hash(b"hello");
+
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::note_preimage { bytes: b"hello".to_vec().into() },
+            )
+            .execute_returns(hash);
+        assert_eq!(Balances::total_balance_on_hold(&alice()), 15);
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::preimage_status { hash })
+            .expect_cost(3 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((true, false, 5u32));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::preimage_for { hash })
+            .expect_cost(3 * RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(UnboundedBytes::from(b"hello".to_vec()));
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::unnote_preimage { hash })
+            .execute_returns(());
+        assert_eq!(Balances::total_balance_on_hold(&alice()), 0);
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::preimage_status { hash })
+            .execute_returns((false, false, 0u32));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::preimage_for { hash })
+            .execute_error(evm::ExitError::Other("Unable to get preimage".into()));
+    });
+}
+
+#[test]
+fn unnote_preimage_of_another_account_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::note_preimage { bytes: b"hello".to_vec().into() },
+            )
+            .execute_returns(BlakeTwo256::hash(b"hello"));
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::unnote_preimage { hash: BlakeTwo256::hash(b"hello") },
+            )
+            .execute_reverts(|output| String::from_utf8_lossy(output).contains("NotAuthorized"));
+    });
+}
+
+#[test]
+fn request_and_unrequest_preimage_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let hash = H256::repeat_byte(0x11);
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::request_preimage { hash })
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::preimage_status { hash })
+            .execute_returns((false, true, 0u32));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::unrequest_preimage { hash })
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::preimage_status { hash })
+            .execute_returns((false, false, 0u32));
+    });
+}
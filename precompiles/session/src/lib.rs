@@ -0,0 +1,100 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(missing_docs)]
+
+use fp_evm::PrecompileHandle;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::Get,
+};
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{DecodeAll, Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::H160;
+use sp_runtime::traits::{Dispatchable, OpaqueKeys};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// The length of the longest public key in session keys, a compressed ECDSA key.
+const MAX_PUBLIC_KEY_LEN: usize = 33;
+
+/// `MaxValidators` bounds the validator set, e.g. by the consensus `MaxAuthorities`.
+pub struct SessionPrecompile<Runtime, MaxValidators>(PhantomData<(Runtime, MaxValidators)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, MaxValidators> SessionPrecompile<Runtime, MaxValidators>
+where
+    Runtime: pallet_evm::Config + pallet_session::Config,
+    MaxValidators: Get<u32>,
+    Runtime::ValidatorId: From<Runtime::AccountId> + Into<H160>,
+    Runtime::RuntimeCall: From<pallet_session::Call<Runtime>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+{
+    /// Sets the session keys of the caller's validator from the next session on. `keys` is the
+    /// SCALE encoding of the runtime's session keys, as returned by `author_rotateKeys`.
+    #[precompile::public("setKeys(bytes,bytes)")]
+    fn set_keys(
+        h: &mut impl PrecompileHandle,
+        keys: UnboundedBytes,
+        proof: UnboundedBytes,
+    ) -> EvmResult<()> {
+        let keys: Vec<u8> = keys.into();
+        let keys = Runtime::Keys::decode_all(&mut &*keys)
+            .map_err(|_| RevertReason::custom("Unable to decode session keys").in_field("keys"))?;
+        let call = pallet_session::Call::<Runtime>::set_keys { keys, proof: proof.into() };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// Removes the session keys of the caller's validator.
+    #[precompile::public("purgeKeys()")]
+    fn purge_keys(h: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let call = pallet_session::Call::<Runtime>::purge_keys {};
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// Returns the SCALE encoded session keys set for `validator`, or empty bytes if none are.
+    #[precompile::public("nextKeys(address)")]
+    #[precompile::view]
+    fn next_keys(h: &mut impl PrecompileHandle, validator: Address) -> EvmResult<UnboundedBytes> {
+        // Storage item: NextKeys: Keys, a public key of every key type
+        h.record_db_read::<Runtime>(
+            8 + Runtime::ValidatorId::max_encoded_len()
+                + Runtime::Keys::key_ids().len() * MAX_PUBLIC_KEY_LEN,
+        )?;
+        let validator: Runtime::ValidatorId =
+            Runtime::AddressMapping::into_account_id(validator.0).into();
+        let keys = pallet_session::NextKeys::<Runtime>::get(validator);
+        Ok(keys.map(|keys| keys.encode()).unwrap_or_default().into())
+    }
+
+    /// Returns the validators of the current session.
+    #[precompile::public("validators()")]
+    #[precompile::view]
+    fn validators(h: &mut impl PrecompileHandle) -> EvmResult<Vec<Address>> {
+        // Storage item: Validators: Vec<ValidatorId>, bounded by `MaxValidators`
+        let max_validators = MaxValidators::get() as usize;
+        h.record_db_read::<Runtime>(
+            parity_scale_codec::Compact(max_validators as u32).encoded_size()
+                + max_validators * Runtime::ValidatorId::max_encoded_len(),
+        )?;
+        let validators = pallet_session::Pallet::<Runtime>::validators();
+        Ok(validators.into_iter().map(|validator| Address(validator.into())).collect())
+    }
+
+    #[precompile::public("currentIndex()")]
+    #[precompile::view]
+    fn current_index(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: CurrentIndex: SessionIndex
+        h.record_db_read::<Runtime>(u32::max_encoded_len())?;
+        Ok(pallet_session::Pallet::<Runtime>::current_index())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::SessionPrecompile;
+use frame_support::traits::{ConstU32, ConstU64};
+use sp_core::crypto::{key_types::DUMMY, KeyTypeId};
+use sp_runtime::{
+    impl_opaque_keys,
+    testing::UintAuthorityId,
+    traits::{ConvertInto, OpaqueKeys},
+    BuildStorage,
+};
+
+/// The bound of the validator set the precompile charges reads by.
+pub type MaxValidators = ConstU32<10>;
+
+impl_opaque_keys! {
+    pub struct MockSessionKeys {
+        pub dummy: UintAuthorityId,
+    }
+}
+
+pub fn session_keys(id: u64) -> MockSessionKeys {
+    MockSessionKeys { dummy: id.into() }
+}
+
+precompile_test_utils::mock_runtime! {
+    precompile: SessionPrecompile<Runtime, MaxValidators>,
+    address: 2011,
+    pallets: {
+        Session: pallet_session,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000), (charlie(), 1_000_000)],
+    genesis: |storage| {
+        pallet_session::GenesisConfig::<Runtime> {
+            keys: vec![(alice(), alice(), session_keys(1))],
+        }
+        .assimilate_storage(storage)
+        .unwrap();
+    },
+}
+
+pub struct TestSessionHandler;
+
+impl pallet_session::SessionHandler<AccountId> for TestSessionHandler {
+    const KEY_TYPE_IDS: &'static [KeyTypeId] = &[DUMMY];
+
+    fn on_genesis_session<Ks: OpaqueKeys>(_: &[(AccountId, Ks)]) {}
+
+    fn on_new_session<Ks: OpaqueKeys>(_: bool, _: &[(AccountId, Ks)], _: &[(AccountId, Ks)]) {}
+
+    fn on_disabled(_: u32) {}
+}
+
+impl pallet_session::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = AccountId;
+    type ValidatorIdOf = ConvertInto;
+    type ShouldEndSession = pallet_session::PeriodicSessions<ConstU64<10>, ConstU64<0>>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<ConstU64<10>, ConstU64<0>>;
+    type SessionManager = ();
+    type SessionHandler = TestSessionHandler;
+    type Keys = MockSessionKeys;
+    type WeightInfo = ();
+}
@@ -0,0 +1,100 @@
+use crate::mock::*;
+use parity_scale_codec::Encode;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::H160;
+
+type PCall = crate::SessionPrecompileCall<Runtime, MaxValidators>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+#[test]
+fn session_views_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::validators {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(vec![Address(alice().into())]);
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::current_index {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(0u32);
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::next_keys { validator: Address(alice().into()) },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(UnboundedBytes::from(session_keys(1).encode()));
+    });
+}
+
+#[test]
+fn set_and_purge_keys_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::set_keys {
+                    keys: session_keys(2).encode().into(),
+                    proof: Vec::<u8>::new().into(),
+                },
+            )
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::next_keys { validator: Address(bob().into()) },
+            )
+            .execute_returns(UnboundedBytes::from(session_keys(2).encode()));
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::purge_keys {})
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::next_keys { validator: Address(bob().into()) },
+            )
+            .execute_returns(UnboundedBytes::from(Vec::<u8>::new()));
+    });
+}
+
+#[test]
+fn set_keys_rejects_invalid_keys() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::set_keys { keys: vec![1u8, 2, 3].into(), proof: Vec::<u8>::new().into() },
+            )
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("Unable to decode session keys")
+            });
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::set_keys {
+                    keys: session_keys(1).encode().into(),
+                    proof: Vec::<u8>::new().into(),
+                },
+            )
+            .execute_reverts(|output| String::from_utf8_lossy(output).contains("DuplicatedKey"));
+    });
+}
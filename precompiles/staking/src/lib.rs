@@ -4,27 +4,43 @@
 use fp_evm::PrecompileHandle;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::{AddressMapping, PrecompileFailure};
+use pallet_staking::{RewardDestination, ValidatorPrefs};
+use parity_scale_codec::{Compact, MaxEncodedLen};
 use precompile_utils::prelude::*;
 use sp_core::{Get, H160, U256};
-use sp_runtime::traits::{Dispatchable, StaticLookup};
+use sp_runtime::{
+    traits::{Dispatchable, StaticLookup},
+    Perbill,
+};
+use sp_staking::PagedExposureMetadata;
 use sp_std::{marker::PhantomData, vec::Vec};
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 pub struct StakingPrecompile<Runtime>(PhantomData<Runtime>);
 
+type BalanceOf<Runtime> = <Runtime as pallet_staking::Config>::CurrencyBalance;
+
 #[precompile_utils::precompile]
 impl<Runtime> StakingPrecompile<Runtime>
 where
     Runtime: pallet_evm::Config + pallet_staking::Config,
     Runtime::AccountId: Into<H160>,
-    <Runtime as pallet_staking::Config>::CurrencyBalance: Into<U256>,
+    BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
     Runtime::Lookup: StaticLookup<Source = Runtime::AccountId>,
+    Runtime::RuntimeCall: From<pallet_staking::Call<Runtime>>,
     <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
     Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 {
     #[precompile::public("activeEra()")]
     #[precompile::view]
-    fn active_era(_: &mut impl PrecompileHandle) -> EvmResult<u32> {
-        // TODO: record gas
+    fn active_era(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: ActiveEra: ActiveEraInfo
+        h.record_db_read::<Runtime>(pallet_staking::ActiveEraInfo::max_encoded_len())?;
         let era_info = pallet_staking::Pallet::<Runtime>::active_era()
             .ok_or_else(|| Self::custom_err("Unable to get active era"))?;
         Ok(era_info.index)
@@ -36,33 +52,328 @@ where
         Ok(<Runtime as pallet_staking::Config>::SessionsPerEra::get())
     }
 
+    /// Returns the span index, the start era of the current span, the last era with a nonzero
+    /// slash and the lengths of the prior spans, latest first.
     #[precompile::public("slashingSpans(address)")]
+    #[precompile::view]
     fn slashing_spans(
-        _: &mut impl PrecompileHandle,
+        h: &mut impl PrecompileHandle,
         address: Address,
     ) -> EvmResult<(u32, u32, u32, Vec<u32>)> {
+        // Storage item: SlashingSpans: SlashingSpans, which is unbounded and charged by
+        // `slashing_spans_max_len`
+        h.record_db_read::<Runtime>(Self::account_key_len() + Self::slashing_spans_max_len())?;
         let addr = Runtime::AddressMapping::into_account_id(address.0);
-        let pallet_staking::slashing::SlashingSpans { .. } =
-            pallet_staking::SlashingSpans::<Runtime>::get(addr)
-                .ok_or_else(|| Self::custom_err("Unable to get slashing spans"))?;
-        // XXX: SlashingSpans fields are private
-        unimplemented!()
+        let spans = pallet_staking::SlashingSpans::<Runtime>::get(addr)
+            .ok_or_else(|| Self::custom_err("Unable to get slashing spans"))?;
+
+        let mut iter = spans.iter();
+        let current =
+            iter.next().ok_or_else(|| Self::custom_err("Unable to get slashing spans"))?;
+        let prior = iter.filter_map(|span| span.length).collect();
+        Ok((current.index, current.start, spans.last_nonzero_slash(), prior))
     }
 
     #[precompile::public("erasTotalStake(uint32)")]
     #[precompile::view]
-    fn eras_total_stake(_: &mut impl PrecompileHandle, era: u32) -> EvmResult<U256> {
+    fn eras_total_stake(h: &mut impl PrecompileHandle, era: u32) -> EvmResult<U256> {
+        // Storage item: ErasTotalStake: Balance
+        h.record_db_read::<Runtime>(Self::era_key_len() + BalanceOf::<Runtime>::max_encoded_len())?;
         let total = pallet_staking::ErasTotalStake::<Runtime>::get(era);
         Ok(total.into())
     }
 
     #[precompile::public("erasValidatorReward(uint32)")]
-    fn eras_validator_reward(_: &mut impl PrecompileHandle, era: u32) -> EvmResult<U256> {
+    #[precompile::view]
+    fn eras_validator_reward(h: &mut impl PrecompileHandle, era: u32) -> EvmResult<U256> {
+        // Storage item: ErasValidatorReward: Balance
+        h.record_db_read::<Runtime>(Self::era_key_len() + BalanceOf::<Runtime>::max_encoded_len())?;
         let reward = pallet_staking::ErasValidatorReward::<Runtime>::get(era)
             .ok_or_else(|| Self::custom_err("Unable to get eras validator reward"))?;
         Ok(reward.into())
     }
 
+    /// Returns the stash, the total and active balance and the unlocking chunks as
+    /// `(value, era)` of the ledger controlled by `controller`.
+    #[precompile::public("ledger(address)")]
+    #[precompile::view]
+    #[allow(clippy::type_complexity)]
+    fn ledger(
+        h: &mut impl PrecompileHandle,
+        controller: Address,
+    ) -> EvmResult<(Address, U256, U256, Vec<(U256, u32)>)> {
+        // Storage item: Ledger: StakingLedger
+        h.record_db_read::<Runtime>(
+            Self::account_key_len() + pallet_staking::StakingLedger::<Runtime>::max_encoded_len(),
+        )?;
+        let controller = Runtime::AddressMapping::into_account_id(controller.0);
+        let pallet_staking::StakingLedger { stash, total, active, unlocking, .. } =
+            pallet_staking::Ledger::<Runtime>::get(controller)
+                .ok_or_else(|| Self::custom_err("Unable to get ledger"))?;
+
+        Ok((
+            Address(stash.into()),
+            total.into(),
+            active.into(),
+            unlocking.into_iter().map(|chunk| (chunk.value.into(), chunk.era)).collect(),
+        ))
+    }
+
+    /// Returns the controller of `stash`.
+    #[precompile::public("bonded(address)")]
+    #[precompile::view]
+    fn bonded(h: &mut impl PrecompileHandle, stash: Address) -> EvmResult<Address> {
+        // Storage item: Bonded: AccountId
+        h.record_db_read::<Runtime>(
+            Self::account_key_len() + Runtime::AccountId::max_encoded_len(),
+        )?;
+        let stash = Runtime::AddressMapping::into_account_id(stash.0);
+        let controller = pallet_staking::Bonded::<Runtime>::get(stash)
+            .ok_or_else(|| Self::custom_err("Unable to get bonded controller"))?;
+        Ok(Address(controller.into()))
+    }
+
+    /// Returns the targets, the era the nominations were submitted in and whether they are
+    /// suppressed.
+    #[precompile::public("nominators(address)")]
+    #[precompile::view]
+    fn nominators(
+        h: &mut impl PrecompileHandle,
+        stash: Address,
+    ) -> EvmResult<(Vec<Address>, u32, bool)> {
+        // Storage item: Nominators: Nominations
+        h.record_db_read::<Runtime>(
+            Self::account_key_len() + pallet_staking::Nominations::<Runtime>::max_encoded_len(),
+        )?;
+        let stash = Runtime::AddressMapping::into_account_id(stash.0);
+        let pallet_staking::Nominations { targets, submitted_in, suppressed } =
+            pallet_staking::Nominators::<Runtime>::get(stash)
+                .ok_or_else(|| Self::custom_err("Unable to get nominations"))?;
+
+        Ok((
+            targets.into_iter().map(|target| Address(target.into())).collect(),
+            submitted_in,
+            suppressed,
+        ))
+    }
+
+    /// Returns the commission in parts per billion and whether the validator blocks new
+    /// nominations.
+    #[precompile::public("validators(address)")]
+    #[precompile::view]
+    fn validators(h: &mut impl PrecompileHandle, stash: Address) -> EvmResult<(u32, bool)> {
+        // Storage item: Validators: ValidatorPrefs
+        h.record_db_read::<Runtime>(Self::account_key_len() + ValidatorPrefs::max_encoded_len())?;
+        let stash = Runtime::AddressMapping::into_account_id(stash.0);
+        let ValidatorPrefs { commission, blocked } =
+            pallet_staking::Validators::<Runtime>::try_get(stash)
+                .map_err(|_| Self::custom_err("Unable to get validator preferences"))?;
+        Ok((commission.deconstruct(), blocked))
+    }
+
+    /// Returns the total and own stake, the number of nominators and the number of exposure
+    /// pages of `validator` in `era`.
+    #[precompile::public("erasStakersOverview(uint32,address)")]
+    #[precompile::view]
+    fn eras_stakers_overview(
+        h: &mut impl PrecompileHandle,
+        era: u32,
+        validator: Address,
+    ) -> EvmResult<(U256, U256, u32, u32)> {
+        // Storage item: ErasStakersOverview: PagedExposureMetadata
+        h.record_db_read::<Runtime>(
+            Self::era_key_len()
+                + Self::account_key_len()
+                + PagedExposureMetadata::<BalanceOf<Runtime>>::max_encoded_len(),
+        )?;
+        let validator = Runtime::AddressMapping::into_account_id(validator.0);
+        let PagedExposureMetadata { total, own, nominator_count, page_count } =
+            pallet_staking::ErasStakersOverview::<Runtime>::get(era, validator)
+                .ok_or_else(|| Self::custom_err("Unable to get eras stakers overview"))?;
+        Ok((total.into(), own.into(), nominator_count, page_count))
+    }
+
+    #[precompile::public("currentEra()")]
+    #[precompile::view]
+    fn current_era(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: CurrentEra: EraIndex
+        h.record_db_read::<Runtime>(u32::max_encoded_len())?;
+        pallet_staking::CurrentEra::<Runtime>::get()
+            .ok_or_else(|| Self::custom_err("Unable to get current era"))
+    }
+
+    #[precompile::public("minNominatorBond()")]
+    #[precompile::view]
+    fn min_nominator_bond(h: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        // Storage item: MinNominatorBond: Balance
+        h.record_db_read::<Runtime>(BalanceOf::<Runtime>::max_encoded_len())?;
+        Ok(pallet_staking::MinNominatorBond::<Runtime>::get().into())
+    }
+
+    #[precompile::public("minValidatorBond()")]
+    #[precompile::view]
+    fn min_validator_bond(h: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        // Storage item: MinValidatorBond: Balance
+        h.record_db_read::<Runtime>(BalanceOf::<Runtime>::max_encoded_len())?;
+        Ok(pallet_staking::MinValidatorBond::<Runtime>::get().into())
+    }
+
+    #[precompile::public("validatorCount()")]
+    #[precompile::view]
+    fn validator_count(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: ValidatorCount: u32
+        h.record_db_read::<Runtime>(u32::max_encoded_len())?;
+        Ok(pallet_staking::ValidatorCount::<Runtime>::get())
+    }
+
+    /// `payee` is the `RewardDestination` variant: 0 - Staked, 1 - Stash, 2 - Controller,
+    /// 3 - Account (`account`), 4 - None. `account` is ignored unless `payee` is 3.
+    #[precompile::public("bond(uint256,uint8,address)")]
+    fn bond(
+        h: &mut impl PrecompileHandle,
+        value: U256,
+        payee: u8,
+        account: Address,
+    ) -> EvmResult<()> {
+        let value = Self::u256_to_amount(value)?;
+        let payee = Self::reward_destination(payee, account)?;
+        let call = pallet_staking::Call::<Runtime>::bond { value, payee };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("bondExtra(uint256)")]
+    fn bond_extra(h: &mut impl PrecompileHandle, max_additional: U256) -> EvmResult<()> {
+        let max_additional = Self::u256_to_amount(max_additional)?;
+        let call = pallet_staking::Call::<Runtime>::bond_extra { max_additional };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("unbond(uint256)")]
+    fn unbond(h: &mut impl PrecompileHandle, value: U256) -> EvmResult<()> {
+        let value = Self::u256_to_amount(value)?;
+        let call = pallet_staking::Call::<Runtime>::unbond { value };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("rebond(uint256)")]
+    fn rebond(h: &mut impl PrecompileHandle, value: U256) -> EvmResult<()> {
+        let value = Self::u256_to_amount(value)?;
+        let call = pallet_staking::Call::<Runtime>::rebond { value };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("withdrawUnbonded(uint32)")]
+    fn withdraw_unbonded(h: &mut impl PrecompileHandle, num_slashing_spans: u32) -> EvmResult<()> {
+        let call = pallet_staking::Call::<Runtime>::withdraw_unbonded { num_slashing_spans };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("nominate(address[])")]
+    fn nominate(h: &mut impl PrecompileHandle, targets: Vec<Address>) -> EvmResult<()> {
+        let targets = targets
+            .into_iter()
+            .map(|target| {
+                Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(target.0))
+            })
+            .collect();
+        let call = pallet_staking::Call::<Runtime>::nominate { targets };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("chill()")]
+    fn chill(h: &mut impl PrecompileHandle) -> EvmResult<()> {
+        let call = pallet_staking::Call::<Runtime>::chill {};
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// `commission` is in parts per billion, i.e. 1_000_000_000 is 100%.
+    #[precompile::public("validate(uint32,bool)")]
+    fn validate(h: &mut impl PrecompileHandle, commission: u32, blocked: bool) -> EvmResult<()> {
+        let prefs = ValidatorPrefs { commission: Perbill::from_parts(commission), blocked };
+        let call = pallet_staking::Call::<Runtime>::validate { prefs };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    /// `payee` and `account` are the same as in `bond`.
+    #[precompile::public("setPayee(uint8,address)")]
+    fn set_payee(h: &mut impl PrecompileHandle, payee: u8, account: Address) -> EvmResult<()> {
+        let payee = Self::reward_destination(payee, account)?;
+        let call = pallet_staking::Call::<Runtime>::set_payee { payee };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("payoutStakers(address,uint32)")]
+    fn payout_stakers(
+        h: &mut impl PrecompileHandle,
+        validator_stash: Address,
+        era: u32,
+    ) -> EvmResult<()> {
+        let validator_stash = Runtime::AddressMapping::into_account_id(validator_stash.0);
+        let call = pallet_staking::Call::<Runtime>::payout_stakers { validator_stash, era };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    fn reward_destination(
+        payee: u8,
+        account: Address,
+    ) -> MayRevert<RewardDestination<Runtime::AccountId>> {
+        #[allow(deprecated)]
+        let payee = match payee {
+            0 => RewardDestination::Staked,
+            1 => RewardDestination::Stash,
+            2 => RewardDestination::Controller,
+            3 => RewardDestination::Account(Runtime::AddressMapping::into_account_id(account.0)),
+            4 => RewardDestination::None,
+            _ => return Err(RevertReason::custom("Unknown reward destination").in_field("payee")),
+        };
+        Ok(payee)
+    }
+
+    /// The length of a storage map key hashed with `Blake2_128Concat` or `Twox64Concat`.
+    fn account_key_len() -> usize {
+        16 + Runtime::AccountId::max_encoded_len()
+    }
+
+    /// The length of `SlashingSpans` with the prior spans of `HistoryDepth` eras, which cover
+    /// the bonding duration the spans are pruned to.
+    fn slashing_spans_max_len() -> usize {
+        let prior = <Runtime as pallet_staking::Config>::HistoryDepth::get() as usize;
+        3 * sp_staking::EraIndex::max_encoded_len()
+            + Compact::<u32>::max_encoded_len()
+            + prior * sp_staking::EraIndex::max_encoded_len()
+    }
+
+    /// The length of a storage map key hashed with `Twox64Concat` over an era index.
+    fn era_key_len() -> usize {
+        8 + sp_staking::EraIndex::max_encoded_len()
+    }
+
+    fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+        value
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("amount type").into())
+    }
+
     fn custom_err(reason: &'static str) -> PrecompileFailure {
         PrecompileFailure::Error { exit_status: evm::ExitError::Other(reason.into()) }
     }
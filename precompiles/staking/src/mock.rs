@@ -0,0 +1,61 @@
+use crate::StakingPrecompile;
+use frame_election_provider_support::{onchain, SequentialPhragmen};
+use frame_support::{parameter_types, traits::ConstU32};
+use sp_runtime::Perbill;
+use sp_staking::currency_to_vote::SaturatingCurrencyToVote;
+
+precompile_test_utils::mock_runtime! {
+    precompile: StakingPrecompile<Runtime>,
+    address: 2004,
+    pallets: {
+        Staking: pallet_staking,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000)],
+}
+
+parameter_types! {
+    pub const SessionsPerEra: sp_staking::SessionIndex = 6;
+    pub const BondingDuration: sp_staking::EraIndex = 3;
+    pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
+}
+
+pub struct OnChainSeqPhragmen;
+impl onchain::Config for OnChainSeqPhragmen {
+    type System = Runtime;
+    type Solver = SequentialPhragmen<AccountId, Perbill>;
+    type DataProvider = Staking;
+    type WeightInfo = ();
+    type MaxWinners = ConstU32<100>;
+    type Bounds = ();
+}
+
+impl pallet_staking::Config for Runtime {
+    type NominationsQuota = pallet_staking::FixedNominationsQuota<16>;
+    type Currency = Balances;
+    type CurrencyBalance = Balance;
+    type UnixTime = Timestamp;
+    type CurrencyToVote = SaturatingCurrencyToVote;
+    type RewardRemainder = ();
+    type RuntimeEvent = RuntimeEvent;
+    type Slash = ();
+    type Reward = ();
+    type SessionsPerEra = SessionsPerEra;
+    type BondingDuration = BondingDuration;
+    type SlashDeferDuration = ();
+    type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+    type SessionInterface = ();
+    type EraPayout = ();
+    type NextNewSession = ();
+    type MaxExposurePageSize = ConstU32<64>;
+    type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
+    type ElectionProvider = onchain::OnChainExecution<OnChainSeqPhragmen>;
+    type GenesisElectionProvider = Self::ElectionProvider;
+    type VoterList = pallet_staking::UseNominatorsAndValidatorsMap<Self>;
+    type TargetList = pallet_staking::UseValidatorsMap<Self>;
+    type MaxUnlockingChunks = ConstU32<32>;
+    type HistoryDepth = ConstU32<84>;
+    type EventListeners = ();
+    type MaxControllersInDeprecationBatch = ConstU32<100>;
+    type WeightInfo = ();
+    type BenchmarkingConfig = pallet_staking::TestBenchmarkingConfig;
+}
@@ -0,0 +1,224 @@
+use crate::mock::*;
+use frame_support::assert_ok;
+use parity_scale_codec::{Decode, Encode};
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+use sp_runtime::Perbill;
+
+type PCall = crate::StakingPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+#[test]
+fn slashing_spans_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        // `SlashingSpans` can only be constructed by the staking pallet, decode it instead
+        let spans = pallet_staking::slashing::SlashingSpans::decode(
+            &mut &(2u32, 10u32, 8u32, vec![3u32, 1]).encode()[..],
+        )
+        .unwrap();
+        pallet_staking::SlashingSpans::<Runtime>::insert(alice(), spans);
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::slashing_spans { address: Address(alice().into()) },
+            )
+            .expect_no_logs()
+            .execute_returns((2u32, 10u32, 8u32, vec![3u32, 1]));
+    });
+}
+
+#[test]
+fn slashing_spans_fails_without_spans() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::slashing_spans { address: Address(alice().into()) },
+            )
+            .execute_error(evm::ExitError::Other("Unable to get slashing spans".into()));
+    });
+}
+
+#[test]
+fn bond_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::bond { value: U256::from(1000), payee: 0, account: Address(H160::zero()) },
+            )
+            .execute_returns(());
+
+        let ledger = pallet_staking::Ledger::<Runtime>::get(alice()).unwrap();
+        assert_eq!(ledger.active, 1000);
+        assert_eq!(
+            pallet_staking::Payee::<Runtime>::get(alice()),
+            Some(pallet_staking::RewardDestination::Staked)
+        );
+    });
+}
+
+#[test]
+fn bond_rejects_unknown_payee() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::bond { value: U256::from(1000), payee: 5, account: Address(H160::zero()) },
+            )
+            .execute_reverts(|output| {
+                String::from_utf8_lossy(output).contains("Unknown reward destination")
+            });
+    });
+}
+
+#[test]
+fn staking_views_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Staking::bond(
+            RuntimeOrigin::signed(alice()),
+            1000,
+            pallet_staking::RewardDestination::Staked
+        ));
+        assert_ok!(Staking::validate(
+            RuntimeOrigin::signed(alice()),
+            pallet_staking::ValidatorPrefs {
+                commission: Perbill::from_percent(10),
+                blocked: false
+            }
+        ));
+        assert_ok!(Staking::bond(
+            RuntimeOrigin::signed(bob()),
+            500,
+            pallet_staking::RewardDestination::Stash
+        ));
+        assert_ok!(Staking::nominate(RuntimeOrigin::signed(bob()), vec![alice()]));
+        pallet_staking::ValidatorCount::<Runtime>::put(7);
+        pallet_staking::MinNominatorBond::<Runtime>::put(10);
+        pallet_staking::CurrentEra::<Runtime>::put(3);
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::ledger { controller: Address(alice().into()) },
+            )
+            .execute_returns((Address(alice().into()), U256::from(1000), U256::from(1000), vec![]));
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::bonded { stash: Address(bob().into()) },
+            )
+            .execute_returns(Address(bob().into()));
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::nominators { stash: Address(bob().into()) },
+            )
+            .execute_returns((vec![Address(alice().into())], 0u32, false));
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::validators { stash: Address(alice().into()) },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((100_000_000u32, false));
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::validators { stash: Address(bob().into()) },
+            )
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_error(evm::ExitError::Other("Unable to get validator preferences".into()));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::current_era {})
+            .execute_returns(3u32);
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::validator_count {})
+            .execute_returns(7u32);
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::min_nominator_bond {})
+            .execute_returns(U256::from(10));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::min_validator_bond {})
+            .execute_returns(U256::zero());
+    });
+}
+
+#[test]
+fn eras_stakers_overview_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        pallet_staking::ErasStakersOverview::<Runtime>::insert(
+            2,
+            alice(),
+            sp_staking::PagedExposureMetadata {
+                total: 1500,
+                own: 1000,
+                nominator_count: 1,
+                page_count: 1,
+            },
+        );
+
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::eras_stakers_overview { era: 2, validator: Address(alice().into()) },
+            )
+            .execute_returns((U256::from(1500), U256::from(1000), 1u32, 1u32));
+    });
+}
+
+#[test]
+fn views_record_db_read_gas() {
+    ExtBuilder::default().build().execute_with(|| {
+        pallet_staking::ActiveEra::<Runtime>::put(pallet_staking::ActiveEraInfo {
+            index: 2,
+            start: None,
+        });
+        pallet_staking::ErasTotalStake::<Runtime>::insert(2, 1500);
+        pallet_staking::ErasValidatorReward::<Runtime>::insert(2, 300);
+        let read_cost = RuntimeHelper::<Runtime>::db_read_gas_cost();
+
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::active_era {})
+            .expect_cost(read_cost)
+            .execute_returns(2u32);
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::eras_total_stake { era: 2 })
+            .expect_cost(read_cost)
+            .execute_returns(U256::from(1500));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::eras_validator_reward { era: 2 })
+            .expect_cost(read_cost)
+            .execute_returns(U256::from(300));
+        precompiles()
+            .prepare_test(
+                bob(),
+                precompile_address(),
+                PCall::slashing_spans { address: Address(alice().into()) },
+            )
+            .expect_cost(read_cost)
+            .execute_error(evm::ExitError::Other("Unable to get slashing spans".into()));
+        precompiles()
+            .prepare_test(bob(), precompile_address(), PCall::sessions_per_era {})
+            .expect_cost(0)
+            .execute_returns(6u32);
+    });
+}
@@ -0,0 +1,218 @@
+//! Mock runtime shared by the precompile tests.
+//!
+//! `mock_runtime!` declares the parts every precompile mock has in common: the `Runtime` with
+//! `System`, `Balances`, `Timestamp` and `EVM`, the `Precompiles` set serving the precompile under
+//! test at `PRECOMPILE_ADDRESS`, the test accounts and the `ExtBuilder`. Only the pallets the
+//! precompile wraps and their configs are left to the mock of every crate:
+//!
+//! ```ignore
+//! precompile_test_utils::mock_runtime! {
+//!     precompile: FaucetPrecompile<Runtime>,
+//!     address: 2005,
+//!     pallets: {
+//!         Faucet: pallet_faucet,
+//!     },
+//!     balances: vec![(Faucet::account_id(), 1_000_000), (alice(), 1_000)],
+//!     genesis: |storage| {
+//!         // assimilate the genesis of the pallets into `storage`
+//!     },
+//! }
+//! ```
+//!
+//! The expanded code refers to `frame_support`, `frame_system`, `pallet_balances`,
+//! `pallet_timestamp`, `pallet_evm`, `fp_account`, `sp_core`, `sp_io` and `sp_runtime`, which the
+//! using crate has to depend on.
+#![no_std]
+
+/// Declares the mock runtime of a precompile test, see the crate documentation.
+#[macro_export]
+macro_rules! mock_runtime {
+    (
+        precompile: $precompile:ty,
+        address: $address:expr,
+        pallets: { $($pallets:tt)* },
+        balances: $balances:expr,
+        $(genesis: |$storage:ident| $genesis:block,)?
+    ) => {
+        type Block = frame_system::mocking::MockBlock<Runtime>;
+        pub(crate) type AccountId = fp_account::AccountId20;
+        #[allow(dead_code)]
+        pub(crate) type Balance = u128;
+
+        pub const PRECOMPILE_ADDRESS: u64 = $address;
+
+        #[allow(dead_code)]
+        pub fn alice() -> AccountId {
+            sp_core::H160::repeat_byte(0xAA).into()
+        }
+
+        #[allow(dead_code)]
+        pub fn bob() -> AccountId {
+            sp_core::H160::repeat_byte(0xBB).into()
+        }
+
+        #[allow(dead_code)]
+        pub fn charlie() -> AccountId {
+            sp_core::H160::repeat_byte(0xCC).into()
+        }
+
+        frame_support::construct_runtime!(
+            pub enum Runtime
+            {
+                System: frame_system,
+                Balances: pallet_balances,
+                Timestamp: pallet_timestamp,
+                EVM: pallet_evm,
+                $($pallets)*
+            }
+        );
+
+        impl frame_system::Config for Runtime {
+            type BaseCallFilter = frame_support::traits::Everything;
+            type BlockWeights = ();
+            type BlockLength = ();
+            type DbWeight = frame_support::weights::constants::RocksDbWeight;
+            type RuntimeOrigin = RuntimeOrigin;
+            type RuntimeCall = RuntimeCall;
+            type Nonce = u64;
+            type Hash = sp_core::H256;
+            type Hashing = sp_runtime::traits::BlakeTwo256;
+            type AccountId = AccountId;
+            type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+            type Block = Block;
+            type RuntimeEvent = RuntimeEvent;
+            type BlockHashCount = frame_support::traits::ConstU32<250>;
+            type Version = ();
+            type PalletInfo = PalletInfo;
+            type AccountData = pallet_balances::AccountData<u128>;
+            type OnNewAccount = ();
+            type OnKilledAccount = ();
+            type SystemWeightInfo = ();
+            type SS58Prefix = ();
+            type OnSetCode = ();
+            type MaxConsumers = frame_support::traits::ConstU32<16>;
+            type RuntimeTask = ();
+            type SingleBlockMigrations = ();
+            type MultiBlockMigrator = ();
+            type PreInherents = ();
+            type PostInherents = ();
+            type PostTransactions = ();
+        }
+
+        impl pallet_balances::Config for Runtime {
+            type MaxLocks = frame_support::traits::ConstU32<50>;
+            type MaxReserves = ();
+            type ReserveIdentifier = [u8; 8];
+            type Balance = u128;
+            type RuntimeEvent = RuntimeEvent;
+            type DustRemoval = ();
+            type ExistentialDeposit = frame_support::traits::ConstU128<1>;
+            type AccountStore = System;
+            type WeightInfo = ();
+            type FreezeIdentifier = RuntimeFreezeReason;
+            type MaxFreezes = frame_support::traits::ConstU32<1>;
+            type RuntimeHoldReason = RuntimeHoldReason;
+            type RuntimeFreezeReason = RuntimeFreezeReason;
+        }
+
+        impl pallet_timestamp::Config for Runtime {
+            type Moment = u64;
+            type OnTimestampSet = ();
+            type MinimumPeriod = frame_support::traits::ConstU64<5>;
+            type WeightInfo = ();
+        }
+
+        pub struct Precompiles<R>(core::marker::PhantomData<R>);
+
+        impl<R> Default for Precompiles<R> {
+            fn default() -> Self {
+                Self(core::marker::PhantomData)
+            }
+        }
+
+        impl pallet_evm::PrecompileSet for Precompiles<Runtime> {
+            fn execute(
+                &self,
+                handle: &mut impl pallet_evm::PrecompileHandle,
+            ) -> Option<pallet_evm::PrecompileResult> {
+                match handle.code_address() {
+                    a if a == sp_core::H160::from_low_u64_be(PRECOMPILE_ADDRESS) => {
+                        Some(<$precompile as pallet_evm::Precompile>::execute(handle))
+                    },
+                    _ => None,
+                }
+            }
+
+            fn is_precompile(
+                &self,
+                address: sp_core::H160,
+                _gas: u64,
+            ) -> pallet_evm::IsPrecompileResult {
+                pallet_evm::IsPrecompileResult::Answer {
+                    is_precompile: address == sp_core::H160::from_low_u64_be(PRECOMPILE_ADDRESS),
+                    extra_cost: 0,
+                }
+            }
+        }
+
+        frame_support::parameter_types! {
+            pub BlockGasLimit: sp_core::U256 = sp_core::U256::max_value();
+            pub PrecompilesValue: Precompiles<Runtime> = Default::default();
+            pub const WeightPerGas: frame_support::weights::Weight =
+                frame_support::weights::Weight::from_parts(20_000, 0);
+            pub const GasLimitPovSizeRatio: u64 = 4;
+            pub SuicideQuickClearLimit: u32 = 0;
+        }
+
+        impl pallet_evm::Config for Runtime {
+            type FeeCalculator = ();
+            type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+            type WeightPerGas = WeightPerGas;
+            type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
+            type CallOrigin = pallet_evm::EnsureAddressRoot<AccountId>;
+            type WithdrawOrigin = pallet_evm::EnsureAddressNever<AccountId>;
+            type AddressMapping = pallet_evm::IdentityAddressMapping;
+            type Currency = Balances;
+            type RuntimeEvent = RuntimeEvent;
+            type PrecompilesType = Precompiles<Self>;
+            type PrecompilesValue = PrecompilesValue;
+            type ChainId = ();
+            type BlockGasLimit = BlockGasLimit;
+            type Runner = pallet_evm::runner::stack::Runner<Self>;
+            type OnChargeTransaction = ();
+            type OnCreate = ();
+            type FindAuthor = ();
+            type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+            type SuicideQuickClearLimit = SuicideQuickClearLimit;
+            type Timestamp = Timestamp;
+            type WeightInfo = pallet_evm::weights::SubstrateWeight<Self>;
+        }
+
+        #[derive(Default)]
+        pub struct ExtBuilder;
+
+        impl ExtBuilder {
+            pub fn build(self) -> sp_io::TestExternalities {
+                let mut storage = <frame_system::GenesisConfig<Runtime> as sp_runtime::BuildStorage>::build_storage(
+                    &Default::default(),
+                )
+                .unwrap();
+
+                sp_runtime::BuildStorage::assimilate_storage(
+                    &pallet_balances::GenesisConfig::<Runtime> { balances: $balances },
+                    &mut storage,
+                )
+                .unwrap();
+
+                $(
+                    let $storage = &mut storage;
+                    $genesis
+                )?
+
+                let mut ext = sp_io::TestExternalities::from(storage);
+                ext.execute_with(|| System::set_block_number(1));
+                ext
+            }
+        }
+    };
+}
@@ -4,18 +4,31 @@
 use fp_evm::PrecompileHandle;
 use frame_support::{
     dispatch::{GetDispatchInfo, PostDispatchInfo},
-    traits::{Currency, IsType},
+    traits::{tokens::Pay, Currency, IsType},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_evm::{AddressMapping, PrecompileFailure};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use precompile_utils::prelude::*;
 use sp_core::{H160, H256, U256};
-use sp_runtime::traits::{Dispatchable, StaticLookup};
-use sp_std::marker::PhantomData;
+use sp_runtime::{
+    traits::{Dispatchable, StaticLookup},
+    BoundedVec, SaturatedConversion,
+};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
 type BalanceOf<Runtime> = <<Runtime as pallet_treasury::Config>::Currency as Currency<
     <Runtime as frame_system::Config>::AccountId,
 >>::Balance;
 
+type AssetBalanceOf<Runtime> = <<Runtime as pallet_treasury::Config>::Paymaster as Pay>::Balance;
+
 pub struct TreasuryPrecompile<Runtime>(PhantomData<Runtime>);
 
 #[precompile_utils::precompile]
@@ -25,6 +38,10 @@ where
     Runtime::AccountId: Into<H160>,
     Runtime::Hash: IsType<H256>,
     BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec,
+    AssetBalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+    Runtime::AssetKind: Default,
+    Runtime::Beneficiary: Into<H160>,
+    <Runtime::BeneficiaryLookup as StaticLookup>::Source: From<Runtime::AccountId>,
     Runtime::Lookup: StaticLookup<Source = Runtime::AccountId>,
     Runtime::RuntimeCall: From<pallet_treasury::Call<Runtime>>,
     <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
@@ -48,6 +65,172 @@ where
         Ok(())
     }
 
+    /// Spends `amount` to `beneficiary` from the block `valid_from` on, or right away for 0.
+    #[precompile::public("spend(uint256,address,uint32)")]
+    fn spend(
+        h: &mut impl PrecompileHandle,
+        amount: U256,
+        beneficiary: Address,
+        valid_from: u32,
+    ) -> EvmResult<()> {
+        let amount: AssetBalanceOf<Runtime> =
+            amount.try_into().map_err(|_| RevertReason::value_is_too_large("amount type"))?;
+        let beneficiary = Runtime::AddressMapping::into_account_id(beneficiary.0).into();
+        let valid_from = (valid_from != 0).then(|| valid_from.into());
+
+        let call = pallet_treasury::Call::<Runtime>::spend {
+            asset_kind: Box::new(Runtime::AssetKind::default()),
+            amount,
+            beneficiary: Box::new(beneficiary),
+            valid_from,
+        };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("payout(uint32)")]
+    fn payout(h: &mut impl PrecompileHandle, index: u32) -> EvmResult<()> {
+        let call = pallet_treasury::Call::<Runtime>::payout { index };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("checkStatus(uint32)")]
+    fn check_status(h: &mut impl PrecompileHandle, index: u32) -> EvmResult<()> {
+        let call = pallet_treasury::Call::<Runtime>::check_status { index };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("voidSpend(uint32)")]
+    fn void_spend(h: &mut impl PrecompileHandle, index: u32) -> EvmResult<()> {
+        let call = pallet_treasury::Call::<Runtime>::void_spend { index };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("removeApproval(uint32)")]
+    fn remove_approval(h: &mut impl PrecompileHandle, proposal_id: u32) -> EvmResult<()> {
+        let call = pallet_treasury::Call::<Runtime>::remove_approval { proposal_id };
+        let origin = Some(Runtime::AddressMapping::into_account_id(h.context().caller));
+        RuntimeHelper::<Runtime>::try_dispatch(h, origin.into(), call)?;
+        Ok(())
+    }
+
+    #[precompile::public("proposalCount()")]
+    #[precompile::view]
+    fn proposal_count(h: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // Storage item: ProposalCount: ProposalIndex
+        h.record_db_read::<Runtime>(pallet_treasury::ProposalIndex::max_encoded_len())?;
+        Ok(pallet_treasury::ProposalCount::<Runtime>::get())
+    }
+
+    /// Returns the proposer, the value, the beneficiary and the bond of the proposal.
+    #[precompile::public("proposals(uint32)")]
+    #[precompile::view]
+    fn proposals(
+        h: &mut impl PrecompileHandle,
+        proposal_id: u32,
+    ) -> EvmResult<(Address, U256, Address, U256)> {
+        // Storage item: Proposals: Proposal
+        h.record_db_read::<Runtime>(
+            Self::index_key_len()
+                + pallet_treasury::Proposal::<Runtime::AccountId, BalanceOf<Runtime>>::max_encoded_len(),
+        )?;
+        let proposal = pallet_treasury::Proposals::<Runtime>::get(proposal_id)
+            .ok_or_else(|| Self::custom_err("Unable to get proposal"))?;
+        // The fields of `Proposal` are private, so they are read back from its encoding.
+        let (proposer, value, beneficiary, bond) = <(
+            Runtime::AccountId,
+            BalanceOf<Runtime>,
+            Runtime::AccountId,
+            BalanceOf<Runtime>,
+        )>::decode(&mut &proposal.encode()[..])
+        .map_err(|_| Self::custom_err("Unable to decode proposal"))?;
+        Ok((Address(proposer.into()), value.into(), Address(beneficiary.into()), bond.into()))
+    }
+
+    /// Returns the ids of the approved proposals that are paid out in the next spend period.
+    #[precompile::public("approvals()")]
+    #[precompile::view]
+    fn approvals(h: &mut impl PrecompileHandle) -> EvmResult<Vec<u32>> {
+        // Storage item: Approvals: BoundedVec<ProposalIndex, MaxApprovals>
+        h.record_db_read::<Runtime>(BoundedVec::<
+            pallet_treasury::ProposalIndex,
+            Runtime::MaxApprovals,
+        >::max_encoded_len())?;
+        Ok(pallet_treasury::Approvals::<Runtime>::get().into_inner())
+    }
+
+    /// Returns the amount, the beneficiary, the first and the last block the spend can be paid
+    /// out in, and its payment status: 0 for pending, 1 for attempted and 2 for failed.
+    #[precompile::public("spends(uint32)")]
+    #[precompile::view]
+    fn spends(
+        h: &mut impl PrecompileHandle,
+        index: u32,
+    ) -> EvmResult<(U256, Address, u32, u32, u8)> {
+        // Storage item: Spends: SpendStatus
+        h.record_db_read::<Runtime>(
+            Self::index_key_len()
+                + pallet_treasury::SpendStatus::<
+                    Runtime::AssetKind,
+                    AssetBalanceOf<Runtime>,
+                    Runtime::Beneficiary,
+                    BlockNumberFor<Runtime>,
+                    <Runtime::Paymaster as Pay>::Id,
+                >::max_encoded_len(),
+        )?;
+        let spend = pallet_treasury::Spends::<Runtime>::get(index)
+            .ok_or_else(|| Self::custom_err("Unable to get spend"))?;
+        // `SpendStatus` fields are private as well.
+        let (_, amount, beneficiary, valid_from, expire_at, status) =
+            <(
+                Runtime::AssetKind,
+                AssetBalanceOf<Runtime>,
+                Runtime::Beneficiary,
+                BlockNumberFor<Runtime>,
+                BlockNumberFor<Runtime>,
+                pallet_treasury::PaymentState<<Runtime::Paymaster as Pay>::Id>,
+            )>::decode(&mut &spend.encode()[..])
+            .map_err(|_| Self::custom_err("Unable to decode spend"))?;
+        let status = match status {
+            pallet_treasury::PaymentState::Pending => 0,
+            pallet_treasury::PaymentState::Attempted { .. } => 1,
+            pallet_treasury::PaymentState::Failed => 2,
+        };
+        Ok((
+            amount.into(),
+            Address(beneficiary.into()),
+            valid_from.saturated_into(),
+            expire_at.saturated_into(),
+            status,
+        ))
+    }
+
+    /// Returns the free balance of the treasury account.
+    #[precompile::public("potBalance()")]
+    #[precompile::view]
+    fn pot_balance(h: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        // Storage item: System::Account: AccountInfo
+        h.record_db_read::<Runtime>(
+            16 + Runtime::AccountId::max_encoded_len()
+                + frame_system::AccountInfo::<Runtime::Nonce, Runtime::AccountData>::max_encoded_len(),
+        )?;
+        let account = pallet_treasury::Pallet::<Runtime>::account_id();
+        Ok(<Runtime as pallet_treasury::Config>::Currency::free_balance(&account).into())
+    }
+
+    /// The length of a storage map key hashed with `Twox64Concat` over a proposal or spend
+    /// index.
+    fn index_key_len() -> usize {
+        8 + u32::max_encoded_len()
+    }
+
     fn custom_err(reason: &'static str) -> PrecompileFailure {
         PrecompileFailure::Error { exit_status: evm::ExitError::Other(reason.into()) }
     }
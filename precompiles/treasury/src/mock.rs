@@ -0,0 +1,51 @@
+use crate::TreasuryPrecompile;
+use frame_support::{
+    parameter_types,
+    traits::{
+        tokens::{PayFromAccount, UnityAssetBalanceConversion},
+        ConstU128, ConstU32, ConstU64,
+    },
+    PalletId,
+};
+use frame_system::{EnsureRoot, EnsureSigned, EnsureWithSuccess};
+use sp_runtime::{traits::IdentityLookup, Permill};
+
+precompile_test_utils::mock_runtime! {
+    precompile: TreasuryPrecompile<Runtime>,
+    address: 2002,
+    pallets: {
+        Treasury: pallet_treasury,
+    },
+    balances: vec![(alice(), 1_000_000), (bob(), 1_000_000), (charlie(), 1_000_000), (Treasury::account_id(), 1_000_000)],
+}
+
+parameter_types! {
+    pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+    pub const ProposalBond: Permill = Permill::from_percent(5);
+    pub TreasuryAccount: AccountId = Treasury::account_id();
+}
+
+impl pallet_treasury::Config for Runtime {
+    type PalletId = TreasuryPalletId;
+    type Currency = Balances;
+    type ApproveOrigin = EnsureRoot<AccountId>;
+    type RejectOrigin = EnsureSigned<AccountId>;
+    type SpendOrigin = EnsureWithSuccess<EnsureSigned<AccountId>, AccountId, ConstU128<1_000>>;
+    type RuntimeEvent = RuntimeEvent;
+    type OnSlash = ();
+    type ProposalBond = ProposalBond;
+    type ProposalBondMinimum = ConstU128<10>;
+    type ProposalBondMaximum = ();
+    type SpendPeriod = ConstU64<10>;
+    type Burn = ();
+    type BurnDestination = ();
+    type SpendFunds = ();
+    type WeightInfo = ();
+    type MaxApprovals = ConstU32<10>;
+    type AssetKind = ();
+    type Beneficiary = AccountId;
+    type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
+    type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+    type BalanceConverter = UnityAssetBalanceConversion;
+    type PayoutPeriod = ConstU64<10>;
+}
@@ -0,0 +1,152 @@
+use crate::mock::*;
+use frame_support::assert_ok;
+use precompile_utils::{prelude::*, testing::*};
+use sp_core::{H160, U256};
+
+type PCall = crate::TreasuryPrecompileCall<Runtime>;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn precompile_address() -> H160 {
+    H160::from_low_u64_be(PRECOMPILE_ADDRESS)
+}
+
+#[test]
+fn propose_spend_is_visible_in_views() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::propose_spend { value: 1_000.into(), beneficiary: Address(bob().into()) },
+            )
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::proposal_count {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(1u32);
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::proposals { proposal_id: 0 })
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((
+                Address(alice().into()),
+                U256::from(1_000),
+                Address(bob().into()),
+                U256::from(50),
+            ));
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::proposals { proposal_id: 1 })
+            .execute_error(evm::ExitError::Other("Unable to get proposal".into()));
+    });
+}
+
+#[test]
+fn remove_approval_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::propose_spend { value: 1_000.into(), beneficiary: Address(bob().into()) },
+            )
+            .execute_returns(());
+        assert_ok!(pallet_treasury::Approvals::<Runtime>::try_append(0));
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::approvals {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(vec![0u32]);
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::remove_approval { proposal_id: 0 })
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::approvals {})
+            .execute_returns(Vec::<u32>::new());
+    });
+}
+
+#[test]
+fn spend_payout_and_check_status_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::spend {
+                    amount: 500.into(),
+                    beneficiary: Address(bob().into()),
+                    valid_from: 0,
+                },
+            )
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::spends { index: 0 })
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns((U256::from(500), Address(bob().into()), 1u32, 11u32, 0u8));
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::payout { index: 0 })
+            .execute_returns(());
+        assert_eq!(Balances::free_balance(bob()), 1_000_500);
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::spends { index: 0 })
+            .execute_returns((U256::from(500), Address(bob().into()), 1u32, 11u32, 1u8));
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::check_status { index: 0 })
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::spends { index: 0 })
+            .execute_error(evm::ExitError::Other("Unable to get spend".into()));
+    });
+}
+
+#[test]
+fn spend_can_be_delayed_and_voided() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                alice(),
+                precompile_address(),
+                PCall::spend {
+                    amount: 500.into(),
+                    beneficiary: Address(bob().into()),
+                    valid_from: 5,
+                },
+            )
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::spends { index: 0 })
+            .execute_returns((U256::from(500), Address(bob().into()), 5u32, 15u32, 0u8));
+
+        precompiles()
+            .prepare_test(charlie(), precompile_address(), PCall::payout { index: 0 })
+            .execute_reverts(|output| String::from_utf8_lossy(output).contains("EarlyPayout"));
+
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::void_spend { index: 0 })
+            .execute_returns(());
+        assert!(pallet_treasury::Spends::<Runtime>::get(0).is_none());
+    });
+}
+
+#[test]
+fn pot_balance_returns_treasury_free_balance() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(alice(), precompile_address(), PCall::pot_balance {})
+            .expect_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())
+            .execute_returns(U256::from(1_000_000));
+    });
+}
@@ -712,6 +712,9 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 parameter_types! {
     pub AccumulationPeriod: BlockNumber = HOURS * 24;
     pub const FaucetAmount: Balance = 1000 * DOLLARS;
+    pub const MaxEmissionPerPeriod: Balance = 1_000_000 * DOLLARS;
+    pub const DefaultPowDifficulty: u32 = 20;
+    pub const PowValidity: BlockNumber = 20;
     pub const FaucetPalletId: PalletId = PalletId(*b"ATFAUCET");
 }
 
@@ -721,6 +724,12 @@ impl pallet_faucet::Config for Runtime {
     type Currency = Balances;
     type PalletId = FaucetPalletId;
     type FaucetAmount = FaucetAmount;
+    type MaxEmissionPerPeriod = MaxEmissionPerPeriod;
+    type AdminOrigin = EnsureRootOrHalfCouncil;
+    type DefaultPowDifficulty = DefaultPowDifficulty;
+    type PowValidity = PowValidity;
+    type AttestationSignature = Signature;
+    type AttestationSigner = <Signature as Verify>::Signer;
     type WeightInfo = pallet_faucet::weights::FaucetWeight<Runtime>;
 }
 
@@ -1400,7 +1409,9 @@ pub type EventRecord = frame_system::EventRecord<
 /// The runtime migrations per release.
 #[allow(deprecated, missing_docs)]
 pub mod migrations {
-    pub type Unreleased = ();
+    use super::*;
+
+    pub type Unreleased = (pallet_faucet::migrations::v1::MigrateToV1<Runtime>,);
 }
 
 impl fp_self_contained::SelfContainedCall for RuntimeCall {
@@ -1475,6 +1486,7 @@ mod benches {
         [pallet_timestamp, Timestamp]
         [pallet_sudo, Sudo]
         [pallet_evm, EVM]
+        [pallet_faucet, Faucet]
     );
 }
 
@@ -1643,6 +1655,20 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_faucet_runtime_api::FaucetApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn remaining_quota(who: AccountId) -> Balance {
+            Faucet::remaining_quota(&who)
+        }
+
+        fn next_reset_block(who: AccountId) -> Option<BlockNumber> {
+            Faucet::next_reset_block(&who)
+        }
+
+        fn pot_balance() -> Balance {
+            Faucet::pot_balance()
+        }
+    }
+
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
         fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
             build_state::<RuntimeGenesisConfig>(config)
@@ -12,10 +12,14 @@ use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
 
 use pallet_evm_precompile_babe::BabePrecompile;
+use pallet_evm_precompile_collective::CollectivePrecompile;
+use pallet_evm_precompile_elections::ElectionsPrecompile;
+use pallet_evm_precompile_fast_unstake::FastUnstakePrecompile;
 use pallet_evm_precompile_faucet::FaucetPrecompile;
 use pallet_evm_precompile_governance::GovernancePrecompile;
 use pallet_evm_precompile_nomination_pools::NominationPoolsPrecompile;
 use pallet_evm_precompile_preimage::PreimagePrecompile;
+use pallet_evm_precompile_session::SessionPrecompile;
 use pallet_evm_precompile_staking::StakingPrecompile;
 use pallet_evm_precompile_treasury::TreasuryPrecompile;
 
@@ -58,6 +62,17 @@ where
             a if a == hash(2005) => Some(FaucetPrecompile::<Runtime>::execute(handle)),
             a if a == hash(2006) => Some(NominationPoolsPrecompile::<Runtime>::execute(handle)),
             a if a == hash(2007) => Some(BabePrecompile::<Runtime>::execute(handle)),
+            a if a == hash(2008) => {
+                Some(CollectivePrecompile::<Runtime, CouncilCollective>::execute(handle))
+            },
+            a if a == hash(2009) => {
+                Some(CollectivePrecompile::<Runtime, TechnicalCollective>::execute(handle))
+            },
+            a if a == hash(2010) => Some(ElectionsPrecompile::<Runtime>::execute(handle)),
+            a if a == hash(2011) => {
+                Some(SessionPrecompile::<Runtime, MaxAuthorities>::execute(handle))
+            },
+            a if a == hash(2012) => Some(FastUnstakePrecompile::<Runtime>::execute(handle)),
             _ => None,
         }
     }
@@ -92,6 +107,9 @@ impl DispatchValidateT<AccountId, RuntimeCall> for DispatchCallFilter {
                 | RuntimeCall::Preimage(..)
                 | RuntimeCall::NominationPools(..)
                 | RuntimeCall::Treasury(..)
+                | RuntimeCall::Council(..)
+                | RuntimeCall::TechnicalCommittee(..)
+                | RuntimeCall::FastUnstake(..)
         ) {
             None
         } else if info.pays_fee == Pays::No || info.class == DispatchClass::Mandatory {
@@ -0,0 +1,69 @@
+// requests funds from the faucet with an unsigned transaction carrying a proof of work
+
+import { ApiPromise, WsProvider } from '@polkadot/api';
+import { blake2AsU8a, cryptoWaitReady } from '@polkadot/util-crypto';
+
+function leadingZeros(hash) {
+    let zeros = 0;
+    for (const byte of hash) {
+        if (byte === 0) {
+            zeros += 8;
+            continue;
+        }
+        zeros += Math.clz32(byte) - 24;
+        break;
+    }
+    return zeros;
+}
+
+// mirrors `pallet_faucet::Pallet::solve_pow`
+function solvePow(api, blockHash, who, amount, difficulty) {
+    for (let nonce = 0n; ; nonce++) {
+        const payload = api.createType('(H256, AccountId, Balance, u64)', [blockHash, who, amount, nonce]);
+        if (leadingZeros(blake2AsU8a(payload.toU8a())) >= difficulty) {
+            return nonce;
+        }
+    }
+}
+
+async function requestFunds(endpoint, who, amount) {
+    await cryptoWaitReady();
+
+    const wsProvider = new WsProvider(endpoint);
+    const api = await ApiPromise.create({ provider: wsProvider });
+
+    const header = await api.rpc.chain.getHeader();
+    const blockNumber = header.number.toNumber();
+    const difficulty = (await api.query.faucet.powDifficulty()).toNumber();
+
+    console.log(`Solving proof of work with difficulty ${difficulty}...`);
+    const nonce = solvePow(api, header.hash, who, amount, difficulty);
+
+    const unsub = await api.tx.faucet
+        .requestFunds(who, amount, blockNumber, nonce)
+        .send(async ({ status, events }) => {
+            console.log(`Current status: ${status.type}`);
+
+            if (status.isInBlock) {
+                console.log(`Transaction included at blockHash ${status.asInBlock}`);
+                events.forEach(({ event: { data, method, section }, phase }) => {
+                    console.log(`\t' ${phase}: ${section}.${method}:: ${data}`);
+                });
+                unsub();
+                await api.disconnect();
+            }
+        });
+}
+
+async function main() {
+    const [, , endpoint, recipientAddress, amount] = process.argv;
+
+    if (!endpoint || !recipientAddress || !amount) {
+        console.error('Usage: node faucet.js <endpoint> <recipientAddress> <amount>');
+        process.exit(1);
+    }
+
+    await requestFunds(endpoint, recipientAddress, amount);
+}
+
+main().catch(console.error);